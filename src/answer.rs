@@ -0,0 +1,93 @@
+use std::fmt::{Display, Error, Formatter};
+
+use crate::{json, toml::Value};
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    Lines(Vec<String>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+impl From<Value> for Answer {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Int(n) => Answer::Int(n),
+            Value::Str(s) => Answer::Str(s),
+            Value::Array(lines) => Answer::Lines(lines),
+        }
+    }
+}
+
+impl From<&Answer> for json::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => json::Value::Int(*n),
+            Answer::Str(s) => json::Value::Str(s.clone()),
+            Answer::Lines(lines) => {
+                json::Value::Array(lines.iter().map(|line| line.as_str().into()).collect())
+            }
+        }
+    }
+}
+
+impl TryFrom<&json::Value> for Answer {
+    type Error = String;
+
+    fn try_from(value: &json::Value) -> Result<Self, Self::Error> {
+        match value {
+            json::Value::Int(n) => Ok(Answer::Int(*n)),
+            json::Value::Str(s) => Ok(Answer::Str(s.clone())),
+            json::Value::Array(lines) => lines
+                .iter()
+                .map(|line| line.as_str().map(str::to_string))
+                .collect::<Option<_>>()
+                .map(Answer::Lines)
+                .ok_or_else(|| "Multi-line answers should be an array of strings".to_string()),
+            _ => Err("Answer should be a number, string or array of strings".to_string()),
+        }
+    }
+}
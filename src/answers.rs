@@ -0,0 +1,227 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::{Display, Error, Formatter},
+    fs, io,
+    path::PathBuf,
+};
+
+use crate::{toml, Answer, Part};
+
+/// Environment variable used to override the default answers directory.
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+
+/// Known-good answers for a year, as stored in `answers/<year>.toml`.
+///
+/// Each day is a table with a key for each solved part, see [`toml::parse`]:
+///
+/// ```toml
+/// [day01]
+/// part1 = 54239
+/// part2 = "text answer"
+/// ```
+///
+/// Multi-line answers are written as an array of strings.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<Answer>; 2]>,
+}
+
+#[derive(Debug)]
+pub struct AnswersError {
+    pub path: Option<PathBuf>,
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match (&self.path, self.line) {
+            (Some(path), 0) => write!(f, "{}: ", path.display())?,
+            (Some(path), line) => write!(f, "{}:{}: ", path.display(), line)?,
+            (None, 0) => (),
+            (None, line) => write!(f, "line {}: ", line)?,
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// There's no recorded answer to compare against.
+    Unknown,
+}
+
+/// The directory holding the answer files, `answers/` unless overridden by [`ANSWERS_DIR_VAR`].
+pub fn answers_dir() -> PathBuf {
+    env::var_os(ANSWERS_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("answers"))
+}
+
+/// The location of the answer file for a given year.
+pub fn answers_path(year: u16) -> PathBuf {
+    answers_dir().join(format!("{}.toml", year))
+}
+
+impl Answers {
+    /// Load the answers for a year, treating a missing file as having no answers.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = answers_path(year);
+        let src = match fs::read_to_string(&path) {
+            Ok(src) => src,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(AnswersError {
+                    path: Some(path),
+                    line: 0,
+                    reason: e.to_string(),
+                })
+            }
+        };
+
+        Self::parse(&src).map_err(|e| AnswersError {
+            path: Some(path),
+            ..e
+        })
+    }
+
+    pub fn parse(src: &str) -> Result<Self, AnswersError> {
+        let tables = toml::parse(src).map_err(|e| AnswersError {
+            path: None,
+            line: e.line,
+            reason: e.reason,
+        })?;
+
+        let mut answers = Self::default();
+        for table in tables {
+            let err = |line: usize, reason: String| AnswersError {
+                path: None,
+                line,
+                reason,
+            };
+
+            // Tables are named like 'day01'
+            let day = table
+                .name
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| {
+                    err(
+                        table.line,
+                        format!("Expected a header like '[day01]', found '[{}]'", table.name),
+                    )
+                })?;
+            if answers.days.contains_key(&day) {
+                return Err(err(
+                    table.line,
+                    format!("Day {} is listed more than once", day),
+                ));
+            }
+
+            let mut parts = [None, None];
+            for (key, value, line) in table.entries {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    key => {
+                        return Err(err(
+                            line,
+                            format!("Unknown key '{}', expected part1 or part2", key),
+                        ))
+                    }
+                };
+                parts[Self::index(part)] = Some(value.into());
+            }
+            answers.days.insert(day, parts);
+        }
+
+        Ok(answers)
+    }
+
+    fn index(part: Part) -> usize {
+        match part {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?[Self::index(part)].as_ref()
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_tables_to_days_and_parts() {
+        let answers =
+            Answers::parse("[day01]\npart1 = 1\npart2 = 2\n\n[day10]\npart2 = 3").unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Int(1)));
+        assert_eq!(answers.get(1, Part::Two), Some(&Answer::Int(2)));
+        assert_eq!(answers.get(10, Part::One), None);
+        assert_eq!(answers.get(10, Part::Two), Some(&Answer::Int(3)));
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn rejects_bad_keys() {
+        let err = Answers::parse("[day01]\npart3 = 1").unwrap_err();
+        assert_eq!(err.reason, "Unknown key 'part3', expected part1 or part2");
+
+        let err = Answers::parse("[dayone]\npart1 = 1").unwrap_err();
+        assert_eq!(
+            err.reason,
+            "Expected a header like '[day01]', found '[dayone]'"
+        );
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("[day05]\npart1 = 35").unwrap();
+
+        assert_eq!(answers.check(5, Part::One, &35.into()), Verdict::Pass);
+        assert_eq!(
+            answers.check(5, Part::One, &36.into()),
+            Verdict::Fail {
+                expected: 35.into()
+            }
+        );
+        assert_eq!(answers.check(5, Part::Two, &35.into()), Verdict::Unknown);
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        let err = Answers::parse("[day01]\npart1 = 1\npart3 = 2").unwrap_err();
+        assert_eq!(err.line, 3);
+
+        let err = Answers::parse("part1 = 1").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = Answers::parse("[day01]\n[day1]").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let err = Answers::parse("[day01]\npart1 = 1\npart1 = 2").unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
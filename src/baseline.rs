@@ -0,0 +1,315 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Error, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    bench::{Stats, Timing},
+    json::{self, object, Value},
+    Part,
+};
+
+/// Slowdowns smaller than this are treated as noise, however large they are relatively.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(1);
+
+/// Timings saved from an earlier benchmark, to compare later runs against.
+///
+/// Stored as JSON, with every duration in nanoseconds:
+///
+/// ```json
+/// {
+///   "parts": [
+///     {
+///       "year": 2023,
+///       "day": 1,
+///       "part": 1,
+///       "parse": { "min": 1200, "median": 1300, "mean": 1350, "stddev": 40 },
+///       "solve": { "min": 52000, "median": 53000, "mean": 53500, "stddev": 900 }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    parts: BTreeMap<(u16, u8, Part), Record>,
+}
+
+/// The timings of each stage of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+#[derive(Debug)]
+pub struct BaselineError {
+    pub path: Option<PathBuf>,
+    pub reason: String,
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", path.display(), self.reason),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+/// How a part's timing compares to its baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    /// The part has no baseline.
+    New,
+    /// The relative change in the median time, e.g. `0.25` for 25% slower.
+    Within(f64),
+    /// Slower than the baseline by more than the threshold.
+    Regressed(f64),
+}
+
+impl Record {
+    /// The typical time to parse and solve the part.
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+impl From<&Timing> for Record {
+    fn from(timing: &Timing) -> Self {
+        Self {
+            parse: timing.parse,
+            solve: timing.solve,
+        }
+    }
+}
+
+fn stats_to_json(stats: &Stats) -> Value {
+    object! {
+        "min" => stats.min.as_nanos(),
+        "median" => stats.median.as_nanos(),
+        "mean" => stats.mean.as_nanos(),
+        "stddev" => stats.stddev.as_nanos(),
+    }
+}
+
+fn stats_from_json(value: &Value) -> Result<Stats, String> {
+    let field = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_int)
+            .and_then(|nanos| u64::try_from(nanos).ok())
+            .map(Duration::from_nanos)
+            .ok_or_else(|| format!("Expected '{}' to be a number of nanoseconds", key))
+    };
+    Ok(Stats {
+        min: field("min")?,
+        median: field("median")?,
+        mean: field("mean")?,
+        stddev: field("stddev")?,
+    })
+}
+
+impl Baseline {
+    /// Load a baseline, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let err = |reason: String| BaselineError {
+            path: Some(path.to_path_buf()),
+            reason,
+        };
+        match fs::read_to_string(path) {
+            Ok(src) => Self::parse(&src).map_err(|e| err(e.reason)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(err(e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_json().pretty() + "\n").map_err(|e| BaselineError {
+            path: Some(path.to_path_buf()),
+            reason: e.to_string(),
+        })
+    }
+
+    pub fn parse(src: &str) -> Result<Self, BaselineError> {
+        let err = |reason: String| BaselineError { path: None, reason };
+
+        let value = json::parse(src).map_err(|e| err(e.to_string()))?;
+        let parts = value
+            .get("parts")
+            .and_then(Value::as_array)
+            .ok_or_else(|| err("Expected an object with a 'parts' array".to_string()))?;
+
+        let mut baseline = Self::default();
+        for (i, record) in parts.iter().enumerate() {
+            let err = |reason: String| err(format!("Part {} of the baseline: {}", i + 1, reason));
+            let number = |key: &str| {
+                record
+                    .get(key)
+                    .and_then(Value::as_int)
+                    .ok_or_else(|| err(format!("Expected '{}' to be a number", key)))
+            };
+
+            let year = u16::try_from(number("year")?).map_err(|e| err(e.to_string()))?;
+            let day = u8::try_from(number("day")?).map_err(|e| err(e.to_string()))?;
+            let part: Part = number("part")?.to_string().parse().map_err(err)?;
+            let stage = |key: &str| {
+                let value = record
+                    .get(key)
+                    .ok_or_else(|| err(format!("Missing '{}' timings", key)))?;
+                stats_from_json(value).map_err(|e| err(format!("In '{}': {}", key, e)))
+            };
+            let timings = Record {
+                parse: stage("parse")?,
+                solve: stage("solve")?,
+            };
+
+            if baseline.parts.insert((year, day, part), timings).is_some() {
+                return Err(err(format!(
+                    "{} day {} part {} is listed more than once",
+                    year, day, part
+                )));
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn to_json(&self) -> Value {
+        let parts = self
+            .parts
+            .iter()
+            .map(|(&(year, day, part), record)| {
+                object! {
+                    "year" => year,
+                    "day" => day,
+                    "part" => part.number(),
+                    "parse" => stats_to_json(&record.parse),
+                    "solve" => stats_to_json(&record.solve),
+                }
+            })
+            .collect();
+        object! { "parts" => Value::Array(parts) }
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Record> {
+        self.parts.get(&(year, day, part))
+    }
+
+    /// Record the timings of a part, replacing any already saved.
+    pub fn insert(&mut self, year: u16, day: u8, part: Part, record: Record) {
+        self.parts.insert((year, day, part), record);
+    }
+
+    /// Compare a part's timings with the baseline.
+    ///
+    /// The median time is used as it is the least affected by outliers, and `threshold` is
+    /// the relative slowdown allowed, e.g. `0.1` for 10%.
+    pub fn compare(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        current: &Record,
+        threshold: f64,
+    ) -> Change {
+        let Some(baseline) = self.get(year, day, part) else {
+            return Change::New;
+        };
+
+        let before = baseline.median();
+        let after = current.median();
+        let change = match before.is_zero() {
+            true => 0.0,
+            false => after.as_secs_f64() / before.as_secs_f64() - 1.0,
+        };
+
+        if change > threshold && after.saturating_sub(before) > NOISE_FLOOR {
+            Change::Regressed(change)
+        } else {
+            Change::Within(change)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(parse_micros: u64, solve_micros: u64) -> Record {
+        let stats = |micros| {
+            let d = Duration::from_micros(micros);
+            Stats {
+                min: d,
+                median: d,
+                mean: d,
+                stddev: Duration::ZERO,
+            }
+        };
+        Record {
+            parse: stats(parse_micros),
+            solve: stats(solve_micros),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut baseline = Baseline::default();
+        baseline.insert(2023, 1, Part::One, record(1, 50));
+        baseline.insert(2023, 21, Part::Two, record(20, 17_000_000));
+
+        let parsed = Baseline::parse(&baseline.to_json().pretty()).unwrap();
+        assert_eq!(parsed, baseline);
+    }
+
+    #[test]
+    fn flags_regressions_over_threshold() {
+        let mut baseline = Baseline::default();
+        baseline.insert(2023, 17, Part::One, record(100, 900));
+
+        let compare = |current: Record| match baseline.compare(2023, 17, Part::One, &current, 0.1) {
+            Change::Within(c) => ("within", (c * 100.0).round()),
+            Change::Regressed(c) => ("regressed", (c * 100.0).round()),
+            Change::New => ("new", 0.0),
+        };
+        assert_eq!(compare(record(100, 950)), ("within", 5.0));
+        assert_eq!(compare(record(100, 800)), ("within", -10.0));
+        assert_eq!(compare(record(100, 1200)), ("regressed", 30.0));
+        assert_eq!(
+            baseline.compare(2023, 17, Part::Two, &record(1, 1), 0.1),
+            Change::New
+        );
+    }
+
+    #[test]
+    fn ignores_slowdowns_below_noise_floor() {
+        let mut baseline = Baseline::default();
+        let tiny = |nanos| Record {
+            solve: Stats {
+                median: Duration::from_nanos(nanos),
+                ..record(0, 0).solve
+            },
+            ..record(0, 0)
+        };
+        baseline.insert(2023, 6, Part::One, tiny(300));
+
+        assert!(matches!(
+            baseline.compare(2023, 6, Part::One, &tiny(900), 0.1),
+            Change::Within(_)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_baselines() {
+        assert!(Baseline::parse("[]").is_err());
+        assert!(Baseline::parse(r#"{"parts": [{"year": 2023, "day": 1, "part": 3}]}"#).is_err());
+
+        let err =
+            Baseline::parse(r#"{"parts": [{"year": 2023, "day": 1, "part": 1}]}"#).unwrap_err();
+        assert!(err.reason.contains("parse"), "{}", err);
+    }
+}
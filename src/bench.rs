@@ -0,0 +1,160 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Day, Error, Params, Part};
+
+/// How many times to run each part when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs made first, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+/// Summary of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+/// The timings of each stage of solving a part, along with its answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Stats {
+    /// Summarise the samples, which shouldn't be empty.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Should be at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Parse the input and solve a part repeatedly, timing each stage separately.
+///
+/// Every iteration parses the input afresh, so days that do most of their work while
+/// parsing aren't hidden behind a cheap solve.
+pub fn run<D: Day>(
+    part: Part,
+    input: &str,
+    params: &Params,
+    config: &BenchConfig,
+) -> Result<Timing, Error> {
+    for _ in 0..config.warmup {
+        let parsed = D::parse(black_box(input))?;
+        black_box(D::solve(&parsed, part, params)?);
+    }
+
+    let iterations = config.iterations.max(1);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+    let mut answer = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = D::parse(black_box(input))?;
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        let solved = black_box(D::solve(&parsed, part, params)?);
+        solve_samples.push(start.elapsed());
+
+        answer = Some(solved);
+    }
+
+    Ok(Timing {
+        answer: answer.expect("Should run at least one iteration"),
+        parse: Stats::new(&parse_samples),
+        solve: Stats::new(&solve_samples),
+    })
+}
+
+/// A duration rounded to a readable unit, e.g. `1.25ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Readable(pub Duration);
+
+impl Display for Readable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let secs = self.0.as_secs_f64();
+        match nanos {
+            0..=999 => write!(f, "{}ns", nanos),
+            1_000..=999_999 => write!(f, "{:.2}µs", secs * 1e6),
+            1_000_000..=999_999_999 => write!(f, "{:.2}ms", secs * 1e3),
+            _ => write!(f, "{:.2}s", secs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let stats = Stats::new(&millis(&[9, 2, 4, 4, 5, 5, 7, 4]));
+
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.mean.as_micros(), 5000);
+        assert_eq!(stats.stddev.as_micros(), 2000);
+
+        let stats = Stats::new(&millis(&[3, 1, 2]));
+        assert_eq!(stats.median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(Readable(Duration::from_nanos(999)).to_string(), "999ns");
+        assert_eq!(Readable(Duration::from_nanos(1_500)).to_string(), "1.50µs");
+        assert_eq!(Readable(Duration::from_micros(1_250)).to_string(), "1.25ms");
+        assert_eq!(
+            Readable(Duration::from_millis(17_000)).to_string(),
+            "17.00s"
+        );
+    }
+}
@@ -0,0 +1,1478 @@
+use std::{
+    collections::HashMap,
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{Answers, Verdict},
+    baseline::{Baseline, Change, Record},
+    bench::{BenchConfig, Readable},
+    client::Client,
+    examples, explore,
+    history::{self, History, Verdict as Judged},
+    input::{self, Source},
+    json::{self, object},
+    log, pool,
+    registry::{self, Entry},
+    scaffold,
+    watch::{self, Line, Snapshot},
+    Answer, Error, Params, Part,
+};
+
+const USAGE: &str = "\
+Usage: advent_of_code <command> [options]
+
+Commands:
+    run <day|all>        Solve a single day, or every solved day in parallel with a summary
+    list                 List the days that have been solved
+    verify [day|all]     Check answers against those recorded in answers/<year>.toml
+    bench <day|all>      Time parsing and solving each part over several runs
+    new <day>            Generate the module, input and example for a new day
+    fetch <day|all>      Download puzzle inputs that haven't been downloaded yet
+    submit <day> -p <part>
+                         Solve a part and submit the answer, unless it's been tried before
+    mark <day> -p <part> <verdict> [answer]
+                         Record what the site said about an answer submitted by hand: correct,
+                         too-high, too-low or wrong [default answer: the current one]
+    history <day>        List the answers tried for a day
+    watch <day>          Solve a day's examples and input again whenever they change
+    explore <day>        Ask questions about a day's parsed input, type 'help' to list them
+
+Input is read from input/<year>/dayNN.txt, or the directory in $AOC_INPUT_DIR, unless
+it's piped in when running a single day.
+Fetching and submitting need the session cookie of a logged in browser in $AOC_SESSION.
+Tried answers are kept in history/<year>/dayNN.json, or the directory in $AOC_HISTORY_DIR,
+and answers known to be wrong are warned about when running.
+
+Options:
+    -y, --year <year>    Puzzle year [default: latest solved]
+    -p, --part <part>    Only solve part 1 or 2
+    -j, --jobs <n>       Number of parts to solve at once [default: number of cores]
+    -i, --input <file>   Solve or explore a single day for another input
+    -f, --format <format>
+                         Print answers as 'text', or 'json' with a line for each part giving
+                         its year, day, part, answer, duration in seconds and error [default: text]
+    -n, --iterations <n> Number of timed runs when benchmarking [default: 10]
+    -w, --warmup <n>     Number of untimed runs before benchmarking [default: 3]
+    -s, --save-baseline <file>
+                         Save benchmark timings, replacing those of the same parts
+    -b, --baseline <file>
+                         Compare benchmark timings against a saved baseline
+    -t, --threshold <percent>
+                         Slowdown allowed before flagging a regression [default: 10]
+    -v, --verbose        Show how each part is solved on stderr, or every step with -vv
+    -h, --help           Print this message";
+
+/// Options that take a value, as (short, long) pairs.
+const OPTIONS: [(&str, &str); 10] = [
+    ("-y", "--year"),
+    ("-p", "--part"),
+    ("-j", "--jobs"),
+    ("-i", "--input"),
+    ("-f", "--format"),
+    ("-n", "--iterations"),
+    ("-w", "--warmup"),
+    ("-s", "--save-baseline"),
+    ("-b", "--baseline"),
+    ("-t", "--threshold"),
+];
+
+/// Default slowdown allowed when comparing against a baseline, as a percentage.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// A JSON object on each line, leaving anything else for stderr.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Format should be 'text' or 'json', found '{}'", s)),
+        }
+    }
+}
+
+/// Which days a command should apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: u16,
+        target: Target,
+        part: Option<Part>,
+        /// Parts to solve at once, when solving every day or printing JSON.
+        jobs: usize,
+        format: Format,
+        /// Input to use instead of the day's own.
+        input: Option<Source>,
+    },
+    List {
+        year: u16,
+    },
+    Verify {
+        year: u16,
+        target: Target,
+    },
+    Bench {
+        year: u16,
+        target: Target,
+        part: Option<Part>,
+        config: BenchConfig,
+        baseline: Option<PathBuf>,
+        save_baseline: Option<PathBuf>,
+        /// Relative slowdown allowed, e.g. `0.1` for 10%.
+        threshold: f64,
+    },
+    New {
+        year: u16,
+        day: u8,
+    },
+    Fetch {
+        year: u16,
+        target: Target,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+    },
+    Mark {
+        year: u16,
+        day: u8,
+        part: Part,
+        verdict: Judged,
+        /// The answer to record, or the one the day gives now if missing.
+        answer: Option<Answer>,
+    },
+    History {
+        year: u16,
+        day: u8,
+    },
+    Watch {
+        year: u16,
+        day: u8,
+        part: Option<Part>,
+    },
+    Explore {
+        year: u16,
+        day: u8,
+        input: Option<Source>,
+    },
+    Help,
+}
+
+/// Arguments split into positionals and options keyed by their long name.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<&'static str, String>,
+    help: bool,
+    /// How many times `-v` was given.
+    verbosity: u8,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut help = false;
+        let mut verbosity = 0u8;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "-h" || arg == "--help" {
+                help = true;
+                continue;
+            }
+            // Allow '--verbose', '-v -v' and '-vv'
+            let vs = match arg.strip_prefix('-') {
+                Some("-verbose") => 1,
+                Some(vs) if !vs.is_empty() && vs.bytes().all(|b| b == b'v') => vs.len(),
+                _ => 0,
+            };
+            if vs > 0 {
+                verbosity = verbosity.saturating_add(vs.min(u8::MAX as usize) as u8);
+                continue;
+            }
+            if !arg.starts_with('-') || arg == "-" {
+                positional.push(arg);
+                continue;
+            }
+
+            // Allow both '--year 2023' and '--year=2023'
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let &(_, long) = OPTIONS
+                .iter()
+                .find(|(short, long)| name == *short || name == *long)
+                .ok_or_else(|| format!("Unknown option '{}'", name))?;
+            let value = match value {
+                Some(value) => value,
+                None => args
+                    .next()
+                    .ok_or_else(|| format!("Option '{}' requires a value", long))?,
+            };
+            if options.insert(long, value).is_some() {
+                return Err(format!("Option '{}' given more than once", long));
+            }
+        }
+
+        Ok(Self {
+            positional,
+            options,
+            help,
+            verbosity,
+        })
+    }
+
+    fn year(&self) -> Result<u16, String> {
+        match self.options.get("--year") {
+            Some(year) => year
+                .parse()
+                .map_err(|_| format!("Year should be a number, found '{}'", year)),
+            None => registry::latest_year().ok_or_else(|| "No days have been solved".to_string()),
+        }
+    }
+
+    fn part(&self) -> Result<Option<Part>, String> {
+        self.options.get("--part").map(|p| p.parse()).transpose()
+    }
+
+    /// A count given by an option, or `default` if it's missing.
+    fn count(&self, option: &str, default: usize) -> Result<usize, String> {
+        match self.options.get(option) {
+            Some(n) => n
+                .parse()
+                .map_err(|_| format!("Option '{}' should be a number, found '{}'", option, n)),
+            None => Ok(default),
+        }
+    }
+
+    /// The input given with `--input`, which only makes sense for a single day.
+    fn source(&self) -> Result<Option<Source>, String> {
+        let source = match self.options.get("--input").map(String::as_str) {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => return Ok(None),
+        };
+        match self.target()? {
+            Target::Day(_) => Ok(Some(source)),
+            Target::All => Err("Option '--input' can only be used for a single day".to_string()),
+        }
+    }
+
+    fn path(&self, option: &str) -> Option<PathBuf> {
+        self.options.get(option).map(PathBuf::from)
+    }
+
+    /// A percentage given by an option as a fraction, or `default` if it's missing.
+    fn fraction(&self, option: &str, default: f64) -> Result<f64, String> {
+        let percent = match self.options.get(option) {
+            Some(p) => p
+                .trim_end_matches('%')
+                .parse::<f64>()
+                .ok()
+                .filter(|p| p.is_finite() && *p >= 0.0)
+                .ok_or_else(|| {
+                    format!("Option '{}' should be a percentage, found '{}'", option, p)
+                })?,
+            None => default,
+        };
+        Ok(percent / 100.0)
+    }
+
+    /// A part that has to be given.
+    fn required_part(&self) -> Result<Part, String> {
+        self.part()?
+            .ok_or_else(|| "Missing part, expected '--part 1' or '--part 2'".to_string())
+    }
+
+    /// A single day, for commands that can't apply to them all.
+    fn day(&self) -> Result<u8, String> {
+        match self.target()? {
+            Target::Day(day) => Ok(day),
+            Target::All => Err("Expected a single day, not 'all'".to_string()),
+        }
+    }
+
+    fn target(&self) -> Result<Target, String> {
+        self.target_or(None)
+    }
+
+    /// Like [`Args::target`], but falling back to `default` when no day is given.
+    fn target_or(&self, default: Option<Target>) -> Result<Target, String> {
+        match self.positional.get(1).map(String::as_str) {
+            Some("all") => Ok(Target::All),
+            Some(day) => day
+                .parse()
+                .map(Target::Day)
+                .map_err(|_| format!("Day should be a number or 'all', found '{}'", day)),
+            None => default.ok_or_else(|| "Missing day, expected a number or 'all'".to_string()),
+        }
+    }
+
+    /// Check that no more than `n` positionals were given, including the command.
+    fn expect_positionals(&self, n: usize) -> Result<(), String> {
+        match self.positional.get(n) {
+            Some(extra) => Err(format!("Unexpected argument '{}'", extra)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        Self::from_args(&Args::parse(args)?)
+    }
+
+    fn from_args(args: &Args) -> Result<Self, String> {
+        if args.help {
+            return Ok(Command::Help);
+        }
+
+        let command = match args.positional.first().map(String::as_str) {
+            Some("run") => {
+                args.expect_positionals(2)?;
+                let jobs = args.count("--jobs", pool::default_jobs())?;
+                if jobs == 0 {
+                    return Err("Option '--jobs' should be at least 1".to_string());
+                }
+                Command::Run {
+                    year: args.year()?,
+                    target: args.target()?,
+                    part: args.part()?,
+                    jobs,
+                    format: args
+                        .options
+                        .get("--format")
+                        .map_or(Ok(Format::Text), |f| f.parse())?,
+                    input: args.source()?,
+                }
+            }
+            Some("list") => {
+                args.expect_positionals(1)?;
+                Command::List { year: args.year()? }
+            }
+            Some("verify") => {
+                args.expect_positionals(2)?;
+                Command::Verify {
+                    year: args.year()?,
+                    target: args.target_or(Some(Target::All))?,
+                }
+            }
+            Some("bench") => {
+                args.expect_positionals(2)?;
+                let default = BenchConfig::default();
+                let config = BenchConfig {
+                    warmup: args.count("--warmup", default.warmup)?,
+                    iterations: args.count("--iterations", default.iterations)?,
+                };
+                if config.iterations == 0 {
+                    return Err("Option '--iterations' should be at least 1".to_string());
+                }
+                Command::Bench {
+                    year: args.year()?,
+                    target: args.target()?,
+                    part: args.part()?,
+                    config,
+                    baseline: args.path("--baseline"),
+                    save_baseline: args.path("--save-baseline"),
+                    threshold: args.fraction("--threshold", DEFAULT_THRESHOLD)?,
+                }
+            }
+            Some("new") => {
+                args.expect_positionals(2)?;
+                match args.target()? {
+                    Target::Day(day) => Command::New {
+                        year: args.year()?,
+                        day,
+                    },
+                    Target::All => return Err("Can only create one day at a time".to_string()),
+                }
+            }
+            Some("fetch") => {
+                args.expect_positionals(2)?;
+                Command::Fetch {
+                    year: args.year()?,
+                    target: args.target()?,
+                }
+            }
+            Some("submit") => {
+                args.expect_positionals(2)?;
+                Command::Submit {
+                    year: args.year()?,
+                    day: args.day()?,
+                    part: args.required_part()?,
+                }
+            }
+            Some("mark") => {
+                args.expect_positionals(4)?;
+                let verdict = args
+                    .positional
+                    .get(2)
+                    .ok_or_else(|| "Missing verdict".to_string())?;
+                Command::Mark {
+                    year: args.year()?,
+                    day: args.day()?,
+                    part: args.required_part()?,
+                    verdict: verdict.parse()?,
+                    answer: args.positional.get(3).map(|answer| parse_answer(answer)),
+                }
+            }
+            Some("watch") => {
+                args.expect_positionals(2)?;
+                Command::Watch {
+                    year: args.year()?,
+                    day: args.day()?,
+                    part: args.part()?,
+                }
+            }
+            Some("explore") => {
+                args.expect_positionals(2)?;
+                Command::Explore {
+                    year: args.year()?,
+                    day: args.day()?,
+                    input: args.source()?,
+                }
+            }
+            Some("history") => {
+                args.expect_positionals(2)?;
+                Command::History {
+                    year: args.year()?,
+                    day: args.day()?,
+                }
+            }
+            Some(command) => return Err(format!("Unknown command '{}'", command)),
+            None => Command::Help,
+        };
+
+        Ok(command)
+    }
+}
+
+/// An answer given on the command line, which is a number if it looks like one.
+fn parse_answer(answer: &str) -> Answer {
+    match answer.parse() {
+        Ok(n) => Answer::Int(n),
+        Err(_) => Answer::from(answer),
+    }
+}
+
+fn print_answer(day: u8, part: Part, answer: &Answer) {
+    match answer {
+        Answer::Lines(_) => println!("Day {:02} part {}:\n{}", day, part, answer),
+        _ => println!("Day {:02} part {}: {}", day, part, answer),
+    }
+}
+
+/// Look up the entries a command should apply to.
+fn select(year: u16, target: Target) -> Result<Vec<&'static Entry>, String> {
+    match target {
+        Target::All => {
+            let entries = registry::year(year);
+            if entries.is_empty() {
+                return Err(format!("No puzzles have been solved for {}", year));
+            }
+            Ok(entries)
+        }
+        Target::Day(day) => registry::get(year, day)
+            .map(|entry| vec![entry])
+            .ok_or_else(|| format!("Day {} of {} isn't solved", day, year)),
+    }
+}
+
+/// The parts to solve, either the one given or both.
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+fn run(
+    year: u16,
+    target: Target,
+    part: Option<Part>,
+    jobs: usize,
+    format: Format,
+    source: Option<Source>,
+) -> Result<(), String> {
+    // Input given on the command line or piped in replaces a single day's own
+    let given = match (source, target) {
+        (Some(source), Target::Day(day)) => Some(source.read(year, day)),
+        (None, Target::Day(_)) => input::piped().transpose(),
+        (_, Target::All) => None,
+    };
+    let given = given.transpose().map_err(|e| e.to_string())?;
+
+    match (format, target) {
+        (Format::Json, _) => return run_json(year, target, part, jobs, given.as_deref()),
+        (Format::Text, Target::All) => return run_all(year, part, jobs),
+        (Format::Text, Target::Day(_)) => (),
+    }
+
+    let selected = select(year, target)?;
+    let parts = parts(part);
+
+    for entry in selected {
+        let input = match &given {
+            Some(input) => input.clone(),
+            None => Source::Default
+                .read(entry.year, entry.day)
+                .map_err(|e| e.to_string())?,
+        };
+        let history = History::load(&history::history_path(entry.year, entry.day))
+            .map_err(|e| e.to_string())?;
+        for &part in parts.iter() {
+            let answer = entry
+                .solve(part, &input, &Params::default())
+                .map_err(|e| e.to_string())?;
+            print_answer(entry.day, part, &answer);
+            // Earlier attempts were for the day's own input, so say nothing about others
+            if given.is_none() {
+                warn_if_wrong(&history, entry.day, part, &answer);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Warn about an answer that earlier attempts show to be wrong.
+fn warn_if_wrong(history: &History, day: u8, part: Part, answer: &Answer) {
+    if let Some(known) = history.known(part, answer) {
+        if known.verdict() != Judged::Correct {
+            eprintln!(
+                "warning: Day {:02} part {}: {} is {}",
+                day,
+                part,
+                single_line(answer),
+                known
+            );
+        }
+    }
+}
+
+fn list(year: u16) -> Result<(), String> {
+    let entries = select(year, Target::All)?;
+
+    println!("{}", year);
+    for entry in entries {
+        println!("  Day {:02}  {}", entry.day, entry.name);
+    }
+
+    let missing = registry::missing(year);
+    if !missing.is_empty() {
+        let missing: Vec<_> = missing.iter().map(|day| day.to_string()).collect();
+        println!("Missing: {}", missing.join(", "));
+    }
+
+    for ((year, day), names) in registry::duplicates() {
+        eprintln!(
+            "warning: {} day {} is registered more than once: {}",
+            year,
+            day,
+            names.join(", ")
+        );
+    }
+    for entry in registry::invalid() {
+        eprintln!(
+            "warning: {} is registered as day {}, which isn't part of the event",
+            entry.name, entry.day
+        );
+    }
+
+    Ok(())
+}
+
+/// Print rows aligned into columns, with a header row.
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        cells.join("  ").trim_end().to_string()
+    };
+
+    println!("{}", format_row(headers.to_vec()));
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+/// Keep answers on a single line so they fit in a table.
+fn single_line(answer: &Answer) -> String {
+    match answer {
+        Answer::Lines(lines) => lines.join("\\n"),
+        _ => answer.to_string(),
+    }
+}
+
+fn verify(year: u16, target: Target) -> Result<(), String> {
+    let selected = select(year, target)?;
+    let answers = Answers::load(year).map_err(|e| e.to_string())?;
+
+    // Failures are reported in the table, so silence the default panic output
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rows = Vec::new();
+    let mut failures = 0;
+    for entry in selected {
+        let input = Source::Default.read(entry.year, entry.day);
+        for part in Part::ALL {
+            let (actual, result) = match &input {
+                Ok(input) => match entry.try_solve(part, input, &Params::default()) {
+                    Ok(answer) => {
+                        let result = match answers.check(entry.day, part, &answer) {
+                            Verdict::Pass => "pass".to_string(),
+                            Verdict::Fail { .. } => "FAIL".to_string(),
+                            Verdict::Unknown => "unknown".to_string(),
+                        };
+                        (single_line(&answer), result)
+                    }
+                    Err(e) => (String::new(), format!("FAIL: {}", e)),
+                },
+                Err(e) => (String::new(), format!("FAIL: {}", e)),
+            };
+            if result.starts_with("FAIL") {
+                failures += 1;
+            }
+
+            let expected = answers
+                .get(entry.day, part)
+                .map(single_line)
+                .unwrap_or_default();
+            rows.push(vec![
+                format!("{:02}", entry.day),
+                part.to_string(),
+                expected,
+                actual,
+                result,
+            ]);
+        }
+    }
+
+    panic::set_hook(hook);
+
+    print_table(&["Day", "Part", "Expected", "Actual", "Result"], &rows);
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} of {} answers failed", n, rows.len())),
+    }
+}
+
+/// A part solved by [`solve_parts`].
+struct Solved {
+    entry: &'static Entry,
+    part: Part,
+    answer: Result<Answer, String>,
+    time: Duration,
+}
+
+impl Solved {
+    fn to_json(&self) -> json::Value {
+        object! {
+            "year" => self.entry.year,
+            "day" => self.entry.day,
+            "part" => self.part.number(),
+            "answer" => self.answer.as_ref().ok(),
+            "duration" => self.time.as_secs_f64(),
+            "error" => self.answer.as_ref().err().map(String::as_str),
+        }
+    }
+}
+
+/// Solve parts of each day on a pool of threads, catching any failures.
+fn solve_parts(
+    selected: &[&'static Entry],
+    part: Option<Part>,
+    jobs: usize,
+    given: Option<&str>,
+) -> Vec<Solved> {
+    // A missing input fails only that day's parts, rather than the whole run
+    let inputs: Vec<_> = selected
+        .iter()
+        .map(|entry| match given {
+            Some(input) => Ok(input.to_string()),
+            None => Source::Default.read(entry.year, entry.day),
+        })
+        .collect();
+    let tasks: Vec<_> = selected
+        .iter()
+        .zip(&inputs)
+        .flat_map(|(&entry, input)| parts(part).into_iter().map(move |p| (entry, input, p)))
+        .collect();
+
+    // Failures are reported with the answers, so silence the default panic output
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let solved = pool::map(&tasks, jobs, |&(entry, input, part)| {
+        let start = Instant::now();
+        let answer = match input {
+            Ok(input) => entry
+                .try_solve(part, input, &Params::default())
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        Solved {
+            entry,
+            part,
+            answer,
+            time: start.elapsed(),
+        }
+    });
+    panic::set_hook(hook);
+    solved
+}
+
+/// Solve every part of every day on a pool of threads, printing a summary table.
+fn run_all(year: u16, part: Option<Part>, jobs: usize) -> Result<(), String> {
+    let selected = select(year, Target::All)?;
+    let answers = Answers::load(year).map_err(|e| e.to_string())?;
+
+    let start = Instant::now();
+    let solved = solve_parts(&selected, part, jobs, None);
+    let elapsed = start.elapsed();
+
+    let mut rows = Vec::new();
+    let mut failures = 0;
+    for Solved {
+        entry,
+        part,
+        answer,
+        time,
+    } in solved
+    {
+        let (answer, result) = match answer {
+            Ok(answer) => {
+                let result = match answers.check(entry.day, part, &answer) {
+                    Verdict::Pass => "pass".to_string(),
+                    Verdict::Fail { expected } => {
+                        format!("FAIL: expected {}", single_line(&expected))
+                    }
+                    Verdict::Unknown => "unknown".to_string(),
+                };
+                (single_line(&answer), result)
+            }
+            Err(e) => (String::new(), format!("FAIL: {}", e)),
+        };
+        if result.starts_with("FAIL") {
+            failures += 1;
+        }
+        rows.push(vec![
+            format!("{:02}", entry.day),
+            part.to_string(),
+            answer,
+            Readable(time).to_string(),
+            result,
+        ]);
+    }
+
+    print_table(&["Day", "Part", "Answer", "Time", "Result"], &rows);
+    println!(
+        "Solved {} parts in {} on {} threads",
+        rows.len(),
+        Readable(elapsed),
+        jobs.min(rows.len())
+    );
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} of {} parts failed", n, rows.len())),
+    }
+}
+
+/// Solve each part, printing a line of JSON for each so that they can be read as they come.
+fn run_json(
+    year: u16,
+    target: Target,
+    part: Option<Part>,
+    jobs: usize,
+    given: Option<&str>,
+) -> Result<(), String> {
+    let selected = select(year, target)?;
+    let solved = solve_parts(&selected, part, jobs, given);
+    for solved in solved.iter() {
+        println!("{}", solved.to_json());
+    }
+
+    match solved.iter().filter(|s| s.answer.is_err()).count() {
+        0 => Ok(()),
+        n => Err(format!("{} of {} parts failed", n, solved.len())),
+    }
+}
+
+/// The timings of a single part, as measured by [`measure`].
+struct Measured {
+    year: u16,
+    day: u8,
+    part: Part,
+    record: Record,
+}
+
+fn bench(
+    year: u16,
+    target: Target,
+    part: Option<Part>,
+    config: &BenchConfig,
+    baseline: Option<&Path>,
+    save_baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<(), String> {
+    // Load the baseline first, so a bad path is reported before the slow part
+    let baseline = match baseline {
+        Some(path) if !path.exists() => {
+            return Err(format!("Baseline '{}' doesn't exist", path.display()))
+        }
+        Some(path) => Some((Baseline::load(path).map_err(|e| e.to_string())?, path)),
+        None => None,
+    };
+
+    let measured = measure(year, target, part, config)?;
+    if let Some(path) = save_baseline {
+        save(&measured, path)?;
+    }
+    match baseline {
+        Some((baseline, path)) => compare(&measured, &baseline, path, threshold),
+        None => Ok(()),
+    }
+}
+
+/// Time each part, printing a table of the results.
+fn measure(
+    year: u16,
+    target: Target,
+    part: Option<Part>,
+    config: &BenchConfig,
+) -> Result<Vec<Measured>, String> {
+    let selected = select(year, target)?;
+    let parts = parts(part);
+
+    let mut measured = Vec::new();
+    let mut rows = Vec::new();
+    for entry in selected {
+        let input = Source::Default
+            .read(entry.year, entry.day)
+            .map_err(|e| e.to_string())?;
+        for &part in parts.iter() {
+            let timing = entry
+                .bench(part, &input, &Params::default(), config)
+                .map_err(|e| e.to_string())?;
+            measured.push(Measured {
+                year: entry.year,
+                day: entry.day,
+                part,
+                record: Record::from(&timing),
+            });
+            for (stage, stats) in [("parse", timing.parse), ("solve", timing.solve)] {
+                rows.push(vec![
+                    format!("{:02}", entry.day),
+                    part.to_string(),
+                    stage.to_string(),
+                    Readable(stats.min).to_string(),
+                    Readable(stats.median).to_string(),
+                    Readable(stats.mean).to_string(),
+                    Readable(stats.stddev).to_string(),
+                ]);
+            }
+        }
+    }
+
+    println!(
+        "{} timed runs after {} warm-up runs",
+        config.iterations, config.warmup
+    );
+    print_table(
+        &["Day", "Part", "Stage", "Min", "Median", "Mean", "Stddev"],
+        &rows,
+    );
+    Ok(measured)
+}
+
+/// Print how each part compares to the baseline, failing if any have regressed.
+fn compare(
+    measured: &[Measured],
+    baseline: &Baseline,
+    path: &Path,
+    threshold: f64,
+) -> Result<(), String> {
+    let mut rows = Vec::new();
+    let mut regressions = 0;
+    for Measured {
+        year,
+        day,
+        part,
+        record,
+    } in measured
+    {
+        let before = baseline
+            .get(*year, *day, *part)
+            .map(|before| Readable(before.median()).to_string())
+            .unwrap_or_default();
+        let (change, result) = match baseline.compare(*year, *day, *part, record, threshold) {
+            Change::New => (String::new(), "new"),
+            Change::Within(change) => (format!("{:+.1}%", change * 100.0), "ok"),
+            Change::Regressed(change) => {
+                regressions += 1;
+                (format!("{:+.1}%", change * 100.0), "REGRESSED")
+            }
+        };
+        rows.push(vec![
+            format!("{:02}", day),
+            part.to_string(),
+            before,
+            Readable(record.median()).to_string(),
+            change,
+            result.to_string(),
+        ]);
+    }
+
+    println!(
+        "\nCompared with {}, allowing {}% slowdown",
+        path.display(),
+        threshold * 100.0
+    );
+    print_table(
+        &["Day", "Part", "Baseline", "Current", "Change", "Result"],
+        &rows,
+    );
+    match regressions {
+        0 => Ok(()),
+        n => Err(format!(
+            "{} of {} parts slowed down by more than {}%",
+            n,
+            rows.len(),
+            threshold * 100.0
+        )),
+    }
+}
+
+/// Add the timings to a baseline, creating it if it doesn't exist.
+fn save(measured: &[Measured], path: &Path) -> Result<(), String> {
+    let mut baseline = Baseline::load(path).map_err(|e| e.to_string())?;
+    for m in measured {
+        baseline.insert(m.year, m.day, m.part, m.record);
+    }
+    baseline.save(path).map_err(|e| e.to_string())?;
+    println!("Saved {} timings to {}", measured.len(), path.display());
+    Ok(())
+}
+
+fn new(year: u16, day: u8) -> Result<(), String> {
+    let scaffolded = scaffold::new_day(Path::new("."), year, day).map_err(|e| e.to_string())?;
+    for path in scaffolded.created {
+        println!("Created {}", path.display());
+    }
+    for path in scaffolded.updated {
+        println!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(year: u16, target: Target) -> Result<(), String> {
+    let days: Vec<u8> = match target {
+        Target::All => select(year, Target::All)?.iter().map(|e| e.day).collect(),
+        Target::Day(day) => vec![day],
+    };
+    let mut client = Client::from_env().map_err(|e| e.to_string())?;
+    for day in days {
+        client.input(year, day).map_err(|e| e.to_string())?;
+        println!(
+            "Day {:02}: {}",
+            day,
+            input::day_path(&client.input_dir, year, day).display()
+        );
+    }
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: Part) -> Result<(), String> {
+    let entry = select(year, Target::Day(day))?[0];
+    let input = Source::Default.read(year, day).map_err(|e| e.to_string())?;
+    let answer = entry
+        .solve(part, &input, &Params::default())
+        .map_err(|e| e.to_string())?;
+    print_answer(day, part, &answer);
+
+    // Answers already recorded have been accepted, so there's no need to ask again
+    let answers = Answers::load(year).map_err(|e| e.to_string())?;
+    match answers.check(day, part, &answer) {
+        Verdict::Pass => {
+            println!("Already recorded in answers/{}.toml", year);
+            return Ok(());
+        }
+        Verdict::Fail { expected } => {
+            return Err(format!(
+                "The answer recorded in answers/{}.toml is {}",
+                year, expected
+            ))
+        }
+        Verdict::Unknown => (),
+    }
+
+    let path = history::history_path(year, day);
+    let mut history = History::load(&path).map_err(|e| e.to_string())?;
+    warn_if_wrong(&history, day, part, &answer);
+    let mut client = Client::from_env().map_err(|e| e.to_string())?;
+    let outcome = client.submit(year, day, part, &answer, &mut history);
+    // Save whatever was learned, even if the server's response couldn't be understood
+    history.save(&path).map_err(|e| e.to_string())?;
+
+    let outcome = outcome.map_err(|e| e.to_string())?;
+    println!("{}", outcome);
+    Ok(())
+}
+
+fn mark(
+    year: u16,
+    day: u8,
+    part: Part,
+    verdict: Judged,
+    answer: Option<Answer>,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let entry = select(year, Target::Day(day))?[0];
+            let input = Source::Default.read(year, day).map_err(|e| e.to_string())?;
+            entry
+                .solve(part, &input, &Params::default())
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    let path = history::history_path(year, day);
+    let mut history = History::load(&path).map_err(|e| e.to_string())?;
+    if let Some(correct) = history.correct(part).filter(|&c| c != &answer) {
+        return Err(format!(
+            "Day {:02} part {} was already solved with {}",
+            day, part, correct
+        ));
+    }
+    history.record(part, answer.clone(), verdict);
+    history.save(&path).map_err(|e| e.to_string())?;
+    println!(
+        "Recorded {} as {} for day {:02} part {}",
+        single_line(&answer),
+        verdict,
+        day,
+        part
+    );
+    Ok(())
+}
+
+fn show_history(year: u16, day: u8) -> Result<(), String> {
+    let history = History::load(&history::history_path(year, day)).map_err(|e| e.to_string())?;
+    if history.attempts.is_empty() {
+        println!("No answers have been tried for day {:02} of {}", day, year);
+        return Ok(());
+    }
+
+    let rows: Vec<_> = history
+        .attempts
+        .iter()
+        .map(|attempt| {
+            vec![
+                attempt.part.to_string(),
+                single_line(&attempt.answer),
+                attempt.verdict.to_string(),
+            ]
+        })
+        .collect();
+    print_table(&["Part", "Answer", "Verdict"], &rows);
+    Ok(())
+}
+
+/// What an example or input gave when solved by [`solve_watched`].
+struct Watched {
+    name: String,
+    result: String,
+    /// Whether an example gave its expected answer.
+    verdict: Option<String>,
+}
+
+impl Watched {
+    fn new(name: String, result: String) -> Self {
+        Self {
+            name,
+            result,
+            verdict: None,
+        }
+    }
+}
+
+/// Solve a day's examples and input.
+fn solve_watched(entry: &Entry, part: Option<Part>) -> Vec<Watched> {
+    let mut results = Vec::new();
+    let show = |result: Result<Answer, Error>| match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    };
+
+    match examples::load(entry.year, entry.day) {
+        Ok(day_examples) => {
+            for example in day_examples {
+                for part in parts(part) {
+                    let Some(expected) = example.expected(part) else {
+                        continue;
+                    };
+                    let result = entry.try_solve(part, &example.input, &example.params);
+                    let verdict = match &result {
+                        Ok(answer) if answer == expected => "pass".to_string(),
+                        Ok(_) => format!("FAIL, expected {}", single_line(expected)),
+                        Err(_) => "FAIL".to_string(),
+                    };
+                    results.push(Watched {
+                        name: format!("{} part {}", example.name, part),
+                        result: show(result),
+                        verdict: Some(verdict),
+                    });
+                }
+            }
+        }
+        Err(e) => results.push(Watched::new(
+            "examples".to_string(),
+            format!("error: {}", e),
+        )),
+    }
+
+    match Source::Default.read(entry.year, entry.day) {
+        Ok(input) => {
+            for part in parts(part) {
+                let result = entry.try_solve(part, &input, &Params::default());
+                results.push(Watched::new(format!("input part {}", part), show(result)));
+            }
+        }
+        Err(e) => results.push(Watched::new("input".to_string(), format!("error: {}", e))),
+    }
+    results
+}
+
+fn watch(year: u16, day: u8, part: Option<Part>) -> Result<(), String> {
+    let entry = select(year, Target::Day(day))?[0];
+    let source = scaffold::module_path(year, day);
+    let paths = [
+        input::default_path(year, day),
+        examples::day_dir(year, day),
+        source.clone(),
+    ];
+
+    // Failures are shown with the answers, so silence the default panic output
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut previous: HashMap<String, String> = HashMap::new();
+    let mut snapshot = Snapshot::take(&paths);
+    loop {
+        println!("Day {:02} of {}", day, year);
+        for watched in solve_watched(entry, part) {
+            let Watched {
+                name,
+                result,
+                verdict,
+            } = watched;
+            let verdict = verdict.map(|v| format!(" [{}]", v)).unwrap_or_default();
+            let before = previous.get(&name).filter(|&before| before != &result);
+            match before {
+                Some(before) if !before.contains('\n') && !result.contains('\n') => {
+                    println!("  {}: {} (was {}){}", name, result, before, verdict)
+                }
+                Some(before) => {
+                    println!("  {}: changed{}", name, verdict);
+                    for line in watch::diff(before, &result) {
+                        match line {
+                            Line::Same(line) => println!("      {}", line),
+                            Line::Removed(line) => println!("    - {}", line),
+                            Line::Added(line) => println!("    + {}", line),
+                        }
+                    }
+                }
+                None if result.contains('\n') => println!("  {}:{}\n{}", name, verdict, result),
+                None => println!("  {}: {}{}", name, result, verdict),
+            }
+            previous.insert(name, result);
+        }
+        println!("Watching for changes, press Ctrl-C to stop\n");
+
+        let changed = loop {
+            thread::sleep(watch::POLL_INTERVAL);
+            let next = Snapshot::take(&paths);
+            let changed = snapshot.changed(&next);
+            snapshot = next;
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        for path in changed.iter() {
+            println!("Changed {}", path.display());
+        }
+        // The running binary can't pick up new code, but the answers can still be checked
+        if changed.contains(&source) {
+            eprintln!("warning: rebuild and restart to solve with the changes to the source");
+        }
+    }
+}
+
+fn explore(year: u16, day: u8, source: Option<Source>) -> Result<(), String> {
+    let entry = select(year, Target::Day(day))?[0];
+    if entry.queries.is_empty() {
+        return Err(format!(
+            "Day {} of {} has nothing to explore yet",
+            day, year
+        ));
+    }
+    // Queries are read from stdin, so the input can't be
+    if source == Some(Source::Stdin) {
+        return Err("Can't explore input from stdin, give a file instead".to_string());
+    }
+    let input = source
+        .unwrap_or(Source::Default)
+        .read(year, day)
+        .map_err(|e| e.to_string())?;
+
+    println!(
+        "Exploring {} day {}, asking:\n{}",
+        year,
+        day,
+        explore::help(entry.queries)
+    );
+    entry
+        .explore(&input, &mut explore::terminal())
+        .map_err(|e| e.to_string())
+}
+
+pub fn main(args: impl IntoIterator<Item = String>) -> ExitCode {
+    let command = Args::parse(args).and_then(|args| {
+        log::set_verbosity(args.verbosity);
+        Command::from_args(&args)
+    });
+    let command = match command {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run {
+            year,
+            target,
+            part,
+            jobs,
+            format,
+            input,
+        } => run(year, target, part, jobs, format, input),
+        Command::List { year } => list(year),
+        Command::Verify { year, target } => verify(year, target),
+        Command::Bench {
+            year,
+            target,
+            part,
+            config,
+            baseline,
+            save_baseline,
+            threshold,
+        } => bench(
+            year,
+            target,
+            part,
+            &config,
+            baseline.as_deref(),
+            save_baseline.as_deref(),
+            threshold,
+        ),
+        Command::New { year, day } => new(year, day),
+        Command::Fetch { year, target } => fetch(year, target),
+        Command::Submit { year, day, part } => submit(year, day, part),
+        Command::Mark {
+            year,
+            day,
+            part,
+            verdict,
+            answer,
+        } => mark(year, day, part, verdict, answer),
+        Command::History { year, day } => show_history(year, day),
+        Command::Watch { year, day, part } => watch(year, day, part),
+        Command::Explore { year, day, input } => explore(year, day, input),
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        Args::parse(line.split_whitespace().map(str::to_string))
+    }
+
+    fn command(line: &str) -> Result<Command, String> {
+        Command::parse(line.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn options_take_values_either_way() {
+        let expected = Command::Run {
+            year: 2023,
+            target: Target::Day(5),
+            part: Some(Part::Two),
+            jobs: 3,
+            format: Format::Json,
+            input: None,
+        };
+        assert_eq!(
+            command("run 5 --year 2023 -p 2 --jobs 3 -f json"),
+            Ok(expected)
+        );
+        assert_eq!(
+            command("run --year=2023 5 -p=2 --jobs=3 --format=json"),
+            command("run 5 --year 2023 -p 2 --jobs 3 -f json")
+        );
+        assert_eq!(
+            command("run 5 --year"),
+            Err("Option '--year' requires a value".to_string())
+        );
+    }
+
+    #[test]
+    fn counts_verbosity() {
+        assert_eq!(args("run 1").unwrap().verbosity, 0);
+        assert_eq!(args("run 1 -v").unwrap().verbosity, 1);
+        assert_eq!(args("-vv run 1").unwrap().verbosity, 2);
+        assert_eq!(args("run -v 1 --verbose -vv").unwrap().verbosity, 4);
+        assert_eq!(args("run -v 1").unwrap().positional, ["run", "1"]);
+    }
+
+    #[test]
+    fn rejects_repeated_and_unknown_options() {
+        assert_eq!(
+            command("run 1 -y 2023 --year 2022"),
+            Err("Option '--year' given more than once".to_string())
+        );
+        assert_eq!(
+            command("run 1 --fast"),
+            Err("Unknown option '--fast'".to_string())
+        );
+        assert_eq!(
+            command("run 1 -x=3"),
+            Err("Unknown option '-x'".to_string())
+        );
+        assert_eq!(
+            command("frobnicate 1"),
+            Err("Unknown command 'frobnicate'".to_string())
+        );
+        assert_eq!(command("run 1 -h"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn input_is_only_for_a_single_day() {
+        assert_eq!(
+            command("run all -y 2023 -i other.txt"),
+            Err("Option '--input' can only be used for a single day".to_string())
+        );
+        assert_eq!(
+            command("explore 7 -y 2023 --input -"),
+            Ok(Command::Explore {
+                year: 2023,
+                day: 7,
+                input: Some(Source::Stdin),
+            })
+        );
+        let Ok(Command::Run { input, .. }) = command("run 7 -y 2023 --input=other.txt") else {
+            panic!("Should parse a run of one day");
+        };
+        assert_eq!(input, Some(Source::Path(PathBuf::from("other.txt"))));
+    }
+
+    #[test]
+    fn mark_and_submit_positionals() {
+        assert_eq!(
+            command("mark 3 -y 2023 -p 1 too-high 1234"),
+            Ok(Command::Mark {
+                year: 2023,
+                day: 3,
+                part: Part::One,
+                verdict: Judged::TooHigh,
+                answer: Some(Answer::Int(1234)),
+            })
+        );
+        assert_eq!(
+            command("mark 3 -y 2023 -p 2 wrong abc"),
+            Ok(Command::Mark {
+                year: 2023,
+                day: 3,
+                part: Part::Two,
+                verdict: Judged::Wrong,
+                answer: Some(Answer::from("abc")),
+            })
+        );
+        let Ok(Command::Mark { answer, .. }) = command("mark 3 -y 2023 -p 2 correct") else {
+            panic!("Should parse a mark without an answer");
+        };
+        assert_eq!(answer, None);
+        assert_eq!(
+            command("mark 3 -y 2023 -p 1"),
+            Err("Missing verdict".to_string())
+        );
+        assert_eq!(
+            command("mark 3 -y 2023 -p 1 wrong 12 34"),
+            Err("Unexpected argument '34'".to_string())
+        );
+
+        assert_eq!(
+            command("submit 3 -y 2023 --part 2"),
+            Ok(Command::Submit {
+                year: 2023,
+                day: 3,
+                part: Part::Two,
+            })
+        );
+        assert_eq!(
+            command("submit 3 -y 2023"),
+            Err("Missing part, expected '--part 1' or '--part 2'".to_string())
+        );
+        assert_eq!(
+            command("submit all -y 2023 -p 1"),
+            Err("Expected a single day, not 'all'".to_string())
+        );
+    }
+}
@@ -0,0 +1,630 @@
+use std::{
+    env,
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    history::{History, Verdict},
+    input, Answer, Part,
+};
+
+pub mod mock;
+
+/// The puzzle server.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable used to point the client at another server, e.g. [`mock`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session cookie from a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The least time left between requests, so the server is never flooded.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Identifies the client to the server, as its maintainers ask of automated tools.
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/George-Grainger/advent-of-code)"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub struct ClientError {
+    /// The HTTP status, if the server responded.
+    pub status: Option<u16>,
+    pub reason: String,
+}
+
+impl ClientError {
+    fn new(reason: impl Into<String>) -> Self {
+        Self {
+            status: None,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.status {
+            Some(status) => write!(f, "Server responded {}: {}", status, self.reason),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// What happened to an answer given to [`Client::submit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The server checked the answer.
+    Judged(Verdict),
+    /// The answer has been submitted before, so wasn't sent again.
+    Known(Verdict),
+    /// The server is still making us wait after an earlier answer, so it wasn't sent.
+    Waiting(Duration),
+    /// The server refused to check the answer as one was given too recently.
+    RateLimited(Option<Duration>),
+    /// The part has already been solved, or the part before it hasn't been.
+    WrongLevel,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Outcome::Judged(Verdict::Correct) => write!(f, "That's the right answer"),
+            Outcome::Judged(verdict) => write!(f, "That's not the right answer, it's {}", verdict),
+            Outcome::Known(verdict) => {
+                write!(f, "Not submitted, this answer is already known {}", verdict)
+            }
+            Outcome::Waiting(wait) => write!(
+                f,
+                "Not submitted, the server won't accept answers for another {}s",
+                wait.as_secs()
+            ),
+            Outcome::RateLimited(Some(wait)) => write!(
+                f,
+                "An answer was given too recently, try again in {}s",
+                wait.as_secs()
+            ),
+            Outcome::RateLimited(None) => write!(f, "An answer was given too recently"),
+            Outcome::WrongLevel => write!(
+                f,
+                "Not the right level, this part is either already solved or still locked"
+            ),
+        }
+    }
+}
+
+/// The parts of a URL the client needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Url {
+    https: bool,
+    host: String,
+    port: u16,
+    /// Any path before the puzzle's, without a trailing '/'.
+    path: String,
+}
+
+impl Url {
+    fn parse(url: &str) -> Result<Self, ClientError> {
+        let err = || ClientError::new(format!("Expected an http or https URL, found '{}'", url));
+        let (https, rest) = match url.split_once("://") {
+            Some(("https", rest)) => (true, rest),
+            Some(("http", rest)) => (false, rest),
+            _ => return Err(err()),
+        };
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| err())?),
+            None => (authority, if https { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return Err(err());
+        }
+
+        Ok(Self {
+            https,
+            host: host.to_string(),
+            port,
+            path: path.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+impl Display for Url {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let scheme = if self.https { "https" } else { "http" };
+        write!(f, "{}://{}:{}{}", scheme, self.host, self.port, self.path)
+    }
+}
+
+/// The parts of an HTTP response the client needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    /// Parse a raw response, skipping any informational responses before it.
+    fn parse(raw: &[u8]) -> Result<Self, ClientError> {
+        let raw = String::from_utf8_lossy(raw);
+        let mut rest = raw.as_ref();
+        loop {
+            let (head, body) = rest
+                .split_once("\r\n\r\n")
+                .ok_or_else(|| ClientError::new("Incomplete response from server"))?;
+            let mut lines = head.lines();
+            let status: u16 = lines
+                .next()
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|status| status.parse().ok())
+                .ok_or_else(|| ClientError::new("Invalid status line from server"))?;
+            if (100..200).contains(&status) {
+                rest = body;
+                continue;
+            }
+
+            let chunked = lines.any(|line| {
+                line.split_once(':').is_some_and(|(name, value)| {
+                    name.eq_ignore_ascii_case("transfer-encoding")
+                        && value.trim().eq_ignore_ascii_case("chunked")
+                })
+            });
+            let body = match chunked {
+                true => dechunk(body)?,
+                false => body.to_string(),
+            };
+            return Ok(Self { status, body });
+        }
+    }
+}
+
+/// Join the chunks of a body sent with `Transfer-Encoding: chunked`.
+fn dechunk(mut body: &str) -> Result<String, ClientError> {
+    let err = || ClientError::new("Invalid chunked response from server");
+    let mut joined = String::new();
+    loop {
+        let (size, rest) = body.split_once("\r\n").ok_or_else(err)?;
+        let size = usize::from_str_radix(size.split(';').next().unwrap_or("").trim(), 16)
+            .map_err(|_| err())?;
+        if size == 0 {
+            return Ok(joined);
+        }
+        joined.push_str(rest.get(..size).ok_or_else(err)?);
+        body = rest[size..].strip_prefix("\r\n").ok_or_else(err)?;
+    }
+}
+
+/// Encode a form value, as answers can contain any characters.
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// The text of a page's `<article>`, or of the whole page, without its tags.
+fn page_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| Some(&html[start..start + html[start..].find("</article>")?]))
+        .unwrap_or(html);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The offset of `needle` in `text`, ignoring ASCII case, so it can be used to slice `text`.
+fn find_ignoring_case(text: &str, needle: &str) -> Option<usize> {
+    text.char_indices().map(|(i, _)| i).find(|&i| {
+        text.as_bytes()[i..]
+            .get(..needle.len())
+            .is_some_and(|bytes| bytes.eq_ignore_ascii_case(needle.as_bytes()))
+    })
+}
+
+/// Find how long the server asks us to wait, from e.g. "You have 1m 23s left to wait"
+/// or "please wait one minute before trying again".
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        let left = &rest[..rest.find(" left to wait")?];
+        let mut secs = 0;
+        for part in left.split_whitespace() {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            secs += n * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+        }
+        return Some(Duration::from_secs(secs));
+    }
+
+    let start = find_ignoring_case(text, "please wait ")?;
+    let mut words = text[start + "please wait ".len()..].split_whitespace();
+    let n = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        n => n.parse().ok()?,
+    };
+    let unit = match words.next()?.trim_end_matches('s') {
+        "hour" => 3600,
+        "minute" => 60,
+        "second" => 1,
+        _ => return None,
+    };
+    Some(Duration::from_secs(n * unit))
+}
+
+/// Work out what the server said about a submitted answer, and how long it asks us to
+/// wait before the next one.
+fn parse_outcome(html: &str) -> Result<(Outcome, Option<Duration>), ClientError> {
+    let text = page_text(html);
+    let wait = parse_wait(&text);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Judged(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Outcome::Judged(if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        return Err(ClientError::new(format!(
+            "Couldn't understand the server's response: {}",
+            text.chars().take(200).collect::<String>()
+        )));
+    };
+    Ok((outcome, wait))
+}
+
+/// Fetches inputs and submits answers, caching and rate limiting as it goes.
+#[derive(Debug)]
+pub struct Client {
+    base: Url,
+    session: String,
+    /// Where inputs are cached, see [`input::input_dir`].
+    pub input_dir: PathBuf,
+    /// The least time left between requests, [`MIN_INTERVAL`] by default.
+    pub min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Result<Self, ClientError> {
+        let session = session.trim();
+        if session.is_empty() {
+            return Err(ClientError::new("Session token shouldn't be empty"));
+        }
+        Ok(Self {
+            base: Url::parse(base_url)?,
+            session: session.to_string(),
+            input_dir: input::input_dir(),
+            min_interval: MIN_INTERVAL,
+            last_request: None,
+        })
+    }
+
+    /// A client for the server in [`BASE_URL_VAR`], or the real one, using the session
+    /// token in [`SESSION_VAR`].
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = env::var(SESSION_VAR).map_err(|_| {
+            ClientError::new(format!(
+                "Set {} to the session cookie of a logged in browser",
+                SESSION_VAR
+            ))
+        })?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+        Self::new(&base_url, &session)
+    }
+
+    /// The input for a day, downloading it only if it isn't already cached.
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String, ClientError> {
+        let path = input::day_path(&self.input_dir, year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.is_empty() => return Ok(input),
+            Ok(_) => (),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => {
+                return Err(ClientError::new(format!(
+                    "Failed to read '{}': {}",
+                    path.display(),
+                    e
+                )))
+            }
+        }
+
+        let response = self.request("GET", &format!("/{}/day/{}/input", year, day), None)?;
+        if response.status != 200 {
+            return Err(ClientError {
+                status: Some(response.status),
+                reason: page_text(&response.body),
+            });
+        }
+
+        let write = || -> io::Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, &response.body)
+        };
+        write().map_err(|e| {
+            ClientError::new(format!("Failed to cache '{}': {}", path.display(), e))
+        })?;
+        Ok(response.body)
+    }
+
+    /// Submit an answer, unless `history` shows it's been submitted before or the server
+    /// is still making us wait, recording what the server says in `history`.
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        history: &mut History,
+    ) -> Result<Outcome, ClientError> {
+        if let Some(verdict) = history.verdict(part, answer) {
+            return Ok(Outcome::Known(verdict));
+        }
+        if let Some(wait) = history.wait(SystemTime::now()) {
+            return Ok(Outcome::Waiting(wait));
+        }
+        if let Answer::Lines(_) = answer {
+            return Err(ClientError::new(
+                "Multi-line answers have to be read and submitted by hand",
+            ));
+        }
+
+        let body = format!(
+            "level={}&answer={}",
+            part.number(),
+            url_encode(&answer.to_string())
+        );
+        let path = format!("/{}/day/{}/answer", year, day);
+        let response = self.request("POST", &path, Some(&body))?;
+        if response.status != 200 {
+            return Err(ClientError {
+                status: Some(response.status),
+                reason: page_text(&response.body),
+            });
+        }
+
+        let (outcome, wait) = parse_outcome(&response.body)?;
+        if let Outcome::Judged(verdict) = outcome {
+            history.record(part, answer.clone(), verdict);
+        }
+        if let Some(wait) = wait {
+            history.retry_after = Some(SystemTime::now() + wait);
+        }
+        Ok(outcome)
+    }
+
+    /// Send a request, first waiting long enough since the last one.
+    fn request(
+        &mut self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<Response, ClientError> {
+        if let Some(last) = self.last_request {
+            let next = last + self.min_interval;
+            let now = Instant::now();
+            if next > now {
+                thread::sleep(next - now);
+            }
+        }
+        self.last_request = Some(Instant::now());
+
+        let path = format!("{}{}", self.base.path, path);
+        let raw = match self.base.https {
+            true => self.send_curl(method, &path, body)?,
+            false => self
+                .send_http(method, &path, body)
+                .map_err(|e| ClientError::new(format!("Failed to reach {}: {}", self.base, e)))?,
+        };
+        Response::parse(&raw)
+    }
+
+    /// Send a request over plain HTTP, as used by [`mock`] servers.
+    fn send_http(&self, method: &str, path: &str, body: Option<&str>) -> io::Result<Vec<u8>> {
+        let mut stream = TcpStream::connect((self.base.host.as_str(), self.base.port))?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        let mut request = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\nConnection: close\r\n",
+            method, path, self.base.host, USER_AGENT, self.session
+        );
+        if let Some(body) = body {
+            request += "Content-Type: application/x-www-form-urlencoded\r\n";
+            request += &format!("Content-Length: {}\r\n", body.len());
+        }
+        request += "\r\n";
+        request += body.unwrap_or("");
+        stream.write_all(request.as_bytes())?;
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw)?;
+        Ok(raw)
+    }
+
+    /// Send a request over HTTPS with `curl`, as std has no TLS.
+    ///
+    /// The session is passed on stdin so it doesn't show up in the process list.
+    fn send_curl(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&str>,
+    ) -> Result<Vec<u8>, ClientError> {
+        let url = format!("https://{}:{}{}", self.base.host, self.base.port, path);
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--include", "--http1.1"])
+            .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+            .args(["--request", method, "--header", "@-"]);
+        if let Some(body) = body {
+            command.args(["--data", body]);
+        }
+        let mut child = command
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                ClientError::new(format!("HTTPS requests need curl to be installed: {}", e))
+            })?;
+
+        let headers = format!(
+            "User-Agent: {}\nCookie: session={}\n",
+            USER_AGENT, self.session
+        );
+        child
+            .stdin
+            .take()
+            .expect("Should have piped stdin")
+            .write_all(headers.as_bytes())
+            .map_err(|e| ClientError::new(format!("Failed to run curl: {}", e)))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| ClientError::new(format!("Failed to run curl: {}", e)))?;
+        if !output.status.success() {
+            return Err(ClientError::new(format!(
+                "Failed to reach {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(output.stdout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_urls() {
+        let url = Url::parse("https://adventofcode.com").unwrap();
+        assert_eq!(
+            (url.https, url.host.as_str(), url.port),
+            (true, "adventofcode.com", 443)
+        );
+
+        let url = Url::parse("http://127.0.0.1:8080/aoc/").unwrap();
+        assert_eq!(url.to_string(), "http://127.0.0.1:8080/aoc");
+        assert!(Url::parse("ftp://example.com").is_err());
+        assert!(Url::parse("http://:80").is_err());
+    }
+
+    #[test]
+    fn parses_responses() {
+        let raw = b"HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nHello\r\n7\r\n, world\r\n0\r\n\r\n";
+        let response = Response::parse(raw).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "Hello, world");
+
+        let response = Response::parse(b"HTTP/2 404\r\nA: b\r\n\r\n404 Not Found").unwrap();
+        assert_eq!(
+            (response.status, response.body.as_str()),
+            (404, "404 Not Found")
+        );
+    }
+
+    #[test]
+    fn parses_verdicts_and_waits() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        let outcome = parse_outcome(&page("That's the right answer! <a>[Continue]</a>"));
+        assert_eq!(outcome.unwrap(), (Outcome::Judged(Verdict::Correct), None));
+
+        let outcome = parse_outcome(&page(
+            "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+        ));
+        assert_eq!(
+            outcome.unwrap(),
+            (
+                Outcome::Judged(Verdict::TooHigh),
+                Some(Duration::from_secs(60))
+            )
+        );
+
+        let outcome = parse_outcome(&page(
+            "That's not the right answer. please wait 5 minutes before trying again.",
+        ));
+        assert_eq!(
+            outcome.unwrap(),
+            (
+                Outcome::Judged(Verdict::Wrong),
+                Some(Duration::from_secs(300))
+            )
+        );
+
+        let outcome = parse_outcome(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.",
+        ));
+        let wait = Some(Duration::from_secs(83));
+        assert_eq!(outcome.unwrap(), (Outcome::RateLimited(wait), wait));
+
+        let outcome = parse_outcome(&page(
+            "You don't seem to be solving the right level. Did you already complete it?",
+        ));
+        assert_eq!(outcome.unwrap(), (Outcome::WrongLevel, None));
+        assert!(parse_outcome("<html>Something else</html>").is_err());
+
+        // Multi-byte characters around where the text is cut or searched
+        let err = parse_outcome(&format!("<html>{}</html>", "é".repeat(300))).unwrap_err();
+        assert_eq!(err.to_string().matches('é').count(), 200);
+        assert_eq!(
+            parse_wait("İİ Please wait five minutes"),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn encodes_answers() {
+        assert_eq!(url_encode("abc-123"), "abc-123");
+        assert_eq!(url_encode("a b&c=d"), "a%20b%26c%3Dd");
+    }
+}
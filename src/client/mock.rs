@@ -0,0 +1,327 @@
+//! A stand-in for the puzzle server, so the [`Client`](super::Client) can be tested offline.
+//!
+//! It serves inputs and checks answers the way the real server does, including making
+//! clients wait after a wrong answer, and keeps a log of the requests it was sent.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::Part;
+
+/// How long the real server makes clients wait after a wrong answer.
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// The puzzles a mock server knows about, built up before it's started.
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    session: String,
+    inputs: HashMap<(u16, u8), String>,
+    answers: HashMap<(u16, u8, Part), String>,
+    /// How long clients have to wait after a wrong answer.
+    pub cooldown: Duration,
+}
+
+/// A mock server running on a background thread, stopped when dropped.
+#[derive(Debug)]
+pub struct Running {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<String>>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+/// What the server remembers between requests.
+#[derive(Debug, Default)]
+struct State {
+    solved: HashSet<(u16, u8, Part)>,
+    wrong_at: Option<Instant>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: String,
+    body: String,
+}
+
+impl MockServer {
+    /// A server that only accepts requests with the given session token.
+    pub fn new(session: &str) -> Self {
+        Self {
+            session: session.to_string(),
+            inputs: HashMap::new(),
+            answers: HashMap::new(),
+            cooldown: DEFAULT_COOLDOWN,
+        }
+    }
+
+    pub fn input(mut self, year: u16, day: u8, input: &str) -> Self {
+        self.inputs.insert((year, day), input.to_string());
+        self
+    }
+
+    pub fn answer(mut self, year: u16, day: u8, part: Part, answer: impl ToString) -> Self {
+        self.answers.insert((year, day, part), answer.to_string());
+        self
+    }
+
+    /// Start serving on a free local port.
+    pub fn start(self) -> io::Result<Running> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let requests = Arc::clone(&requests);
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                let mut state = State::default();
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    // A broken connection only affects the client that made it
+                    if let Ok(stream) = stream {
+                        let _ = self.serve(stream, &mut state, &requests);
+                    }
+                }
+            })
+        };
+
+        Ok(Running {
+            addr,
+            requests,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    fn serve(
+        &self,
+        stream: TcpStream,
+        state: &mut State,
+        requests: &Mutex<Vec<String>>,
+    ) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let request = read_request(&mut reader)?;
+        requests
+            .lock()
+            .expect("Request log shouldn't be poisoned")
+            .push(format!("{} {}", request.method, request.path));
+
+        let (status, body) = self.respond(&request, state);
+        let reason = match status {
+            200 => "OK",
+            400 => "Bad Request",
+            _ => "Not Found",
+        };
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            reason,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+
+    fn respond(&self, request: &Request, state: &mut State) -> (u16, String) {
+        let logged_in = request
+            .cookie
+            .split(';')
+            .any(|cookie| cookie.trim() == format!("session={}", self.session));
+
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        let (year, day, action) = match segments[..] {
+            [year, "day", day, action] => match (year.parse(), day.parse()) {
+                (Ok(year), Ok(day)) => (year, day, action),
+                _ => return not_found(),
+            },
+            _ => return not_found(),
+        };
+
+        match (request.method.as_str(), action) {
+            ("GET", "input") if !logged_in => (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            ),
+            ("GET", "input") => match self.inputs.get(&(year, day)) {
+                Some(input) => (200, input.clone()),
+                None => not_found(),
+            },
+            ("POST", "answer") if !logged_in => {
+                (400, "Please log in to submit answers.".to_string())
+            }
+            ("POST", "answer") => (200, article(&self.check(year, day, &request.body, state))),
+            _ => not_found(),
+        }
+    }
+
+    /// Check a submitted answer, giving the text the real server would.
+    fn check(&self, year: u16, day: u8, form: &str, state: &mut State) -> String {
+        let field = |name: &str| {
+            form.split('&')
+                .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                .map(url_decode)
+        };
+        let part = match field("level").as_deref() {
+            Some("1") => Part::One,
+            Some("2") => Part::Two,
+            _ => return "You don't seem to be solving the right level.".to_string(),
+        };
+        let answer = field("answer").unwrap_or_default();
+
+        if let Some(wrong_at) = state.wrong_at {
+            let left = self.cooldown.saturating_sub(wrong_at.elapsed());
+            if !left.is_zero() {
+                return format!(
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait.",
+                    format_wait(left)
+                );
+            }
+        }
+
+        let locked = part == Part::Two && !state.solved.contains(&(year, day, Part::One));
+        let Some(expected) = self.answers.get(&(year, day, part)) else {
+            return "You don't seem to be solving the right level.".to_string();
+        };
+        if locked || state.solved.contains(&(year, day, part)) {
+            return "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_string();
+        }
+
+        if &answer == expected {
+            state.solved.insert((year, day, part));
+            return "That's the right answer!  You are one gold star closer to saving Christmas."
+                .to_string();
+        }
+
+        state.wrong_at = Some(Instant::now());
+        let direction = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(given), Ok(expected)) if given > expected => "; your answer is too high",
+            (Ok(given), Ok(expected)) if given < expected => "; your answer is too low",
+            _ => "",
+        };
+        format!(
+            "That's not the right answer{}.  If you're stuck, make sure you're using the full input data.  Because you have guessed incorrectly, please wait {} before trying again.",
+            direction,
+            match self.cooldown.as_secs() {
+                60 => "one minute".to_string(),
+                secs => format!("{} seconds", secs),
+            }
+        )
+    }
+}
+
+impl Running {
+    /// The base URL to give a [`Client`](super::Client).
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Every request received so far, as `<method> <path>`.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .expect("Request log shouldn't be poisoned")
+            .clone()
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the server up so it sees it's been stopped
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> io::Result<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut words = line.split_whitespace();
+    let method = words.next().unwrap_or_default().to_string();
+    let path = words.next().unwrap_or_default().to_string();
+
+    let mut cookie = String::new();
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "cookie" => cookie = value.trim().to_string(),
+                "content-length" => length = value.trim().parse().unwrap_or(0),
+                _ => (),
+            }
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    Ok(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn url_decode(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = iter.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(b) => bytes.push(b),
+                    None => bytes.extend(b"%".iter().chain(&hex)),
+                }
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn format_wait(wait: Duration) -> String {
+    let secs = wait.as_secs().max(1);
+    match secs / 60 {
+        0 => format!("{}s", secs),
+        mins => format!("{}m {}s", mins, secs % 60),
+    }
+}
+
+fn article(text: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        text
+    )
+}
+
+fn not_found() -> (u16, String) {
+    (404, "404 Not Found".to_string())
+}
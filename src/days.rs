@@ -1,42 +0,0 @@
-mod day01;
-pub use day01::Day01;
-mod day02;
-pub use day02::Day02;
-mod day03;
-pub use day03::Day03;
-mod day04;
-pub use day04::Day04;
-mod day05;
-pub use day05::Day05;
-mod day06;
-pub use day06::Day06;
-mod day07;
-pub use day07::Day07;
-mod day08;
-pub use day08::Day08;
-mod day09;
-pub use day09::Day09;
-mod day10;
-pub use day10::Day10;
-mod day11;
-pub use day11::Day11;
-mod day12;
-pub use day12::Day12;
-mod day13;
-pub use day13::Day13;
-mod day14;
-pub use day14::Day14;
-mod day15;
-pub use day15::Day15;
-mod day16;
-pub use day16::Day16;
-mod day17;
-pub use day17::Day17;
-mod day18;
-pub use day18::Day18;
-mod day19;
-pub use day19::Day19;
-mod day20;
-pub use day20::Day20;
-mod day21;
-pub use day21::Day21;
@@ -0,0 +1,239 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    answers::AnswersError, baseline::BaselineError, client::ClientError, examples::ExampleError,
+    history::HistoryError, input::InputError,
+};
+
+/// Anything that can go wrong while loading and solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input couldn't be parsed.
+    Parse(ParseError),
+    /// The input parsed, but doesn't have an answer in the form the puzzle promises.
+    Solve {
+        day: Option<u8>,
+        reason: String,
+    },
+    /// A parameter was overridden with a value the day can't use.
+    Param {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// A solution panicked, which is always a bug.
+    Panic {
+        day: Option<u8>,
+        message: String,
+    },
+    Input(InputError),
+    Answers(AnswersError),
+    Example(ExampleError),
+    Baseline(BaselineError),
+    History(HistoryError),
+    Client(ClientError),
+}
+
+/// Why and where a puzzle input couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day being parsed, filled in by the registry, see [`Error::in_day`].
+    pub day: Option<u8>,
+    /// Line of the problem, counting from 1, or 0 if it isn't known.
+    pub line: usize,
+    /// Column of the problem in characters, counting from 1, or 0 if it isn't known.
+    pub column: usize,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// An error without a known position, e.g. for missing sections of an input.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: 0,
+            column: 0,
+            reason: reason.into(),
+        }
+    }
+
+    /// An error at `token`, which should be a slice of `input` such as a line or word.
+    ///
+    /// The position is found from where the token sits in memory, so a token that isn't
+    /// borrowed from `input` gives an error without a position.
+    pub fn at(input: &str, token: &str, reason: impl Into<String>) -> Self {
+        let mut error = Self::new(reason);
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= input.len() && input.is_char_boundary(offset) {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            error.line = before.matches('\n').count() + 1;
+            error.column = before[line_start..].chars().count() + 1;
+        }
+        error
+    }
+
+    /// Parse `token`, a slice of `input`, describing what was expected if it's invalid.
+    pub fn parse<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, Self> {
+        token.parse().map_err(|_| {
+            Self::at(
+                input,
+                token,
+                format!("Expected {}, found '{}'", expected, token),
+            )
+        })
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {:02}: ", day)?;
+        }
+        match (self.line, self.column) {
+            (0, _) => (),
+            (line, 0) => write!(f, "line {}: ", line)?,
+            (line, column) => write!(f, "line {} column {}: ", line, column)?,
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Error {
+    /// The input can't be solved, for example because there's no path through a maze.
+    pub fn solve(reason: impl Into<String>) -> Self {
+        Error::Solve {
+            day: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// Record which day the error came from, if it isn't already known.
+    pub fn in_day(mut self, day: u8) -> Self {
+        match &mut self {
+            Error::Parse(ParseError { day: d, .. })
+            | Error::Solve { day: d, .. }
+            | Error::Panic { day: d, .. } => {
+                d.get_or_insert(day);
+            }
+            _ => (),
+        }
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Solve {
+                day: Some(day),
+                reason,
+            } => write!(f, "Day {:02}: {}", day, reason),
+            Error::Solve { day: None, reason } => write!(f, "{}", reason),
+            Error::Panic {
+                day: Some(day),
+                message,
+            } => write!(f, "Day {:02}: panicked: {}", day, message),
+            Error::Panic { day: None, message } => write!(f, "panicked: {}", message),
+            Error::Param { key, value, reason } => write!(
+                f,
+                "Parameter '{}' has invalid value '{}': {}",
+                key, value, reason
+            ),
+            Error::Input(e) => write!(f, "{}", e),
+            Error::Answers(e) => write!(f, "{}", e),
+            Error::Example(e) => write!(f, "{}", e),
+            Error::Baseline(e) => write!(f, "{}", e),
+            Error::History(e) => write!(f, "{}", e),
+            Error::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(e) => Some(e),
+            Error::Input(e) => Some(e),
+            Error::Answers(e) => Some(e),
+            Error::Example(e) => Some(e),
+            Error::Baseline(e) => Some(e),
+            Error::History(e) => Some(e),
+            Error::Client(e) => Some(e),
+            Error::Solve { .. } | Error::Param { .. } | Error::Panic { .. } => None,
+        }
+    }
+}
+
+macro_rules! impl_from_error {
+    ($($variant:ident($t:ty)),*) => {
+        $(
+            impl From<$t> for Error {
+                fn from(e: $t) -> Self {
+                    Error::$variant(e)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_error!(
+    Parse(ParseError),
+    Input(InputError),
+    Answers(AnswersError),
+    Example(ExampleError),
+    Baseline(BaselineError),
+    History(HistoryError),
+    Client(ClientError)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_position_of_token() {
+        let input = "32T3K 765\nT55J5 68x\nKK677 28";
+        let line = input.lines().nth(1).unwrap();
+        let bet = line.split_once(' ').unwrap().1;
+
+        let err = ParseError::parse::<u32>(input, bet, "a bet").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.reason, "Expected a bet, found '68x'");
+
+        let err = ParseError::at(input, input, "start");
+        assert_eq!((err.line, err.column), (1, 1));
+
+        let err = ParseError::at(input, &input[input.len()..], "end");
+        assert_eq!((err.line, err.column), (3, 9));
+    }
+
+    #[test]
+    fn ignores_tokens_from_elsewhere() {
+        let other = String::from("KK677");
+        let err = ParseError::at("KK677 28", &other, "elsewhere");
+        assert_eq!((err.line, err.column), (0, 0));
+    }
+
+    #[test]
+    fn displays_day_and_position() {
+        let input = "a\nbc";
+        let err = Error::from(ParseError::at(input, &input[2..], "Unexpected 'b'")).in_day(7);
+        assert_eq!(err.to_string(), "Day 07: line 2 column 1: Unexpected 'b'");
+
+        let err = Error::solve("No path to the end").in_day(17).in_day(3);
+        assert_eq!(err.to_string(), "Day 17: No path to the end");
+
+        assert_eq!(
+            ParseError::new("Missing parts").to_string(),
+            "Missing parts"
+        );
+    }
+}
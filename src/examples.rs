@@ -0,0 +1,142 @@
+use std::{
+    env,
+    fmt::{Display, Error, Formatter},
+    fs, io,
+    path::PathBuf,
+};
+
+use crate::{toml, toml::Value, Answer, Params, Part};
+
+/// Environment variable used to override the default examples directory.
+pub const EXAMPLES_DIR_VAR: &str = "AOC_EXAMPLES_DIR";
+
+/// Name of the file in each day's directory describing its examples.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+/// A worked example from a puzzle's description.
+///
+/// Each day has a directory of example inputs, along with an `expected.toml` that has a
+/// table for each example:
+///
+/// ```toml
+/// [example]
+/// part1 = 374
+///
+/// [expansion10]
+/// input = "example.txt"
+/// expansion = 10
+/// part2 = 1030
+/// ```
+///
+/// The input defaults to `<name>.txt`, and any keys other than `input`, `part1` and
+/// `part2` are passed to the day as [`Params`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub params: Params,
+    expected: [Option<Answer>; 2],
+}
+
+#[derive(Debug)]
+pub struct ExampleError {
+    pub path: PathBuf,
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Display for ExampleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.line {
+            0 => write!(f, "{}: {}", self.path.display(), self.reason),
+            line => write!(f, "{}:{}: {}", self.path.display(), line, self.reason),
+        }
+    }
+}
+
+impl std::error::Error for ExampleError {}
+
+/// The directory holding the examples, `examples/` unless overridden by [`EXAMPLES_DIR_VAR`].
+pub fn examples_dir() -> PathBuf {
+    env::var_os(EXAMPLES_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("examples"))
+}
+
+/// The directory holding the examples for a given day, `<examples dir>/<year>/dayNN`.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    examples_dir()
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.expected[0].as_ref(),
+            Part::Two => self.expected[1].as_ref(),
+        }
+    }
+}
+
+/// Load the examples for a day, treating a missing directory as having no examples.
+pub fn load(year: u16, day: u8) -> Result<Vec<Example>, ExampleError> {
+    let dir = day_dir(year, day);
+    let path = dir.join(EXPECTED_FILE);
+    let err = |line: usize, reason: String| ExampleError {
+        path: path.clone(),
+        line,
+        reason,
+    };
+
+    let src = match fs::read_to_string(&path) {
+        Ok(src) => src,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(err(0, e.to_string())),
+    };
+    let tables = toml::parse(&src).map_err(|e| err(e.line, e.reason))?;
+
+    let mut examples = Vec::with_capacity(tables.len());
+    for table in tables {
+        let mut input_file = format!("{}.txt", table.name);
+        let mut params = Params::new();
+        let mut expected = [None, None];
+
+        for (key, value, line) in table.entries {
+            match (key.as_str(), value) {
+                ("input", Value::Str(file)) => input_file = file,
+                ("input", _) => return Err(err(line, "Input should be a file name".to_string())),
+                ("part1", value) => expected[0] = Some(value.into()),
+                ("part2", value) => expected[1] = Some(value.into()),
+                (_, Value::Int(n)) => params.insert(key, n),
+                (_, Value::Str(s)) => params.insert(key, s),
+                (_, Value::Array(_)) => {
+                    return Err(err(line, format!("Parameter '{}' can't be an array", key)))
+                }
+            }
+        }
+
+        if expected.iter().all(Option::is_none) {
+            return Err(err(
+                table.line,
+                format!("Example '{}' has no expected answers", table.name),
+            ));
+        }
+
+        let input_path = dir.join(&input_file);
+        let input = fs::read_to_string(&input_path).map_err(|e| ExampleError {
+            path: input_path,
+            line: 0,
+            reason: e.to_string(),
+        })?;
+
+        examples.push(Example {
+            name: table.name,
+            input,
+            params,
+            expected,
+        });
+    }
+
+    Ok(examples)
+}
@@ -0,0 +1,169 @@
+//! Asking questions about a day's parsed input, without recompiling to add prints.
+//!
+//! Each day lists the queries it understands in [`Day::QUERIES`] and answers them in
+//! [`Day::explore`], while this module reads the queries and shows the replies.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{Day, Error};
+
+/// Where queries come from and replies go to.
+pub trait Session {
+    /// The next query, or `None` once there are no more.
+    fn query(&mut self) -> Option<String>;
+    fn reply(&mut self, reply: Result<String, String>);
+}
+
+/// A session reading queries a line at a time, e.g. from a terminal.
+pub struct Repl<R, W> {
+    reader: R,
+    writer: W,
+}
+
+const PROMPT: &str = "> ";
+
+impl<R: BufRead, W: Write> Repl<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+impl<R: BufRead, W: Write> Session for Repl<R, W> {
+    fn query(&mut self) -> Option<String> {
+        // A closed terminal or pipe ends the session the same way as 'quit'
+        write!(self.writer, "{}", PROMPT).ok()?;
+        self.writer.flush().ok()?;
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim().to_string()),
+        }
+    }
+
+    fn reply(&mut self, reply: Result<String, String>) {
+        let _ = match reply {
+            Ok(reply) => writeln!(self.writer, "{}", reply),
+            Err(e) => writeln!(self.writer, "error: {}", e),
+        };
+    }
+}
+
+/// A session on stdin and stdout.
+pub fn terminal() -> Repl<io::StdinLock<'static>, io::Stdout> {
+    Repl::new(io::stdin().lock(), io::stdout())
+}
+
+/// The queries every day understands, on top of its own.
+const BUILT_IN: [(&str, &str); 2] = [("help", "List the queries"), ("quit", "Stop exploring")];
+
+/// The usage and description of each query, aligned into columns.
+pub fn help(queries: &[(&str, &str)]) -> String {
+    let queries: Vec<_> = queries.iter().chain(BUILT_IN.iter()).collect();
+    let width = queries
+        .iter()
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    queries
+        .iter()
+        .map(|(usage, description)| format!("  {:<width$}  {}", usage, description, width = width))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse an input and answer queries about it until the session ends.
+///
+/// A query that fails is reported, and the session goes on.
+pub fn run<D: Day>(input: &str, session: &mut dyn Session) -> Result<(), Error> {
+    let mut parsed = D::parse(input)?;
+
+    while let Some(line) = session.query() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let reply = match words.as_slice() {
+            [] => continue,
+            ["help"] => Ok(help(D::QUERIES)),
+            ["quit"] | ["exit"] => break,
+            words => D::explore(&mut parsed, words),
+        };
+        session.reply(reply);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError};
+
+    /// Counts up from the number it's given, for testing the session.
+    struct Counter;
+
+    impl Day for Counter {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        const QUERIES: &'static [(&'static str, &'static str)] = &[("add <n>", "Add to the count")];
+
+        type Parsed<'a> = i64;
+
+        fn parse(input: &str) -> Result<i64, ParseError> {
+            ParseError::parse(input, input.trim(), "a number")
+        }
+
+        fn problem1(_: &i64) -> Result<Answer, Error> {
+            Err(Error::solve("Counter has no puzzle to solve"))
+        }
+
+        fn problem2(_: &i64) -> Result<Answer, Error> {
+            Err(Error::solve("Counter has no puzzle to solve"))
+        }
+
+        fn explore(count: &mut i64, query: &[&str]) -> Result<String, String> {
+            match query {
+                ["add", n] => {
+                    *count += n.parse::<i64>().map_err(|e| e.to_string())?;
+                    Ok(count.to_string())
+                }
+                _ => Err(format!("Unknown query '{}'", query.join(" "))),
+            }
+        }
+    }
+
+    fn explore(input: &str, queries: &str) -> String {
+        let mut repl = Repl::new(queries.as_bytes(), Vec::new());
+        run::<Counter>(input, &mut repl).unwrap();
+        String::from_utf8(repl.into_writer()).unwrap()
+    }
+
+    #[test]
+    fn answers_queries_until_quit() {
+        let output = explore("5\n", "add 2\n\nadd 3\nquit\nadd 4\n");
+        assert_eq!(output, "> 7\n> > 10\n> ");
+    }
+
+    #[test]
+    fn reports_bad_queries_and_carries_on() {
+        let output = explore("5\n", "take 2\nadd x\nadd 1\n");
+        assert_eq!(
+            output,
+            "> error: Unknown query 'take 2'\n\
+             > error: invalid digit found in string\n\
+             > 6\n> "
+        );
+    }
+
+    #[test]
+    fn lists_queries() {
+        let output = explore("5\n", "help\n");
+        assert!(
+            output.contains("  add <n>  Add to the count\n"),
+            "{}",
+            output
+        );
+        assert!(output.contains("  quit     Stop exploring\n"), "{}", output);
+    }
+}
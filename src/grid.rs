@@ -0,0 +1,331 @@
+//! A rectangle of cells, as most of the map-like puzzle inputs are drawn.
+//!
+//! Positions are [`Point`]s, with `x` counting columns from the left and `y` counting rows
+//! from the top.
+
+use std::{
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    point::{Direction, Point},
+    ParseError,
+};
+
+/// The offsets to every cell touching a cell, clockwise from the top left.
+const SURROUNDING: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Every cell, a row at a time.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its cells, a row at a time.
+    ///
+    /// # Panics
+    ///
+    /// If there aren't exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Should be {} by {} cells",
+            width,
+            height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a grid with a character per cell, where `cell` converts each character or
+    /// explains why it isn't allowed.
+    pub fn parse<F>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        Self::parse_within(input, input, cell)
+    }
+
+    /// Parse a grid from `text`, a slice of `input`, so that errors point at where they
+    /// are in the whole input.
+    pub fn parse_within<F>(input: &str, text: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let width = text.lines().next().map_or(0, |row| row.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(text.len());
+        for row in text.lines() {
+            if row.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("Every row of the grid should have {} characters", width),
+                ));
+            }
+            for (i, c) in row.char_indices() {
+                cells.push(cell(c).map_err(|reason| ParseError::at(input, &row[i..], reason))?);
+            }
+            height += 1;
+        }
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point<usize>) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        match self.contains(position) {
+            true => self.cells.get(position.y * self.width + position.x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        match self.contains(position) {
+            true => self.cells.get_mut(position.y * self.width + position.x),
+            false => None,
+        }
+    }
+
+    /// The cell at a position on the grid repeated infinitely in every direction.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    pub fn get_wrapping(&self, position: Point<i64>) -> &T {
+        let x = position.x.rem_euclid(self.width as i64) as usize;
+        let y = position.y.rem_euclid(self.height as i64) as usize;
+        &self[Point::new(x, y)]
+    }
+
+    /// The position `offset` away, or `None` if that's off the grid.
+    pub fn step(&self, position: Point<usize>, offset: Point<isize>) -> Option<Point<usize>> {
+        let next = position.checked_add_signed(offset)?;
+        self.contains(next).then_some(next)
+    }
+
+    /// The position `offset` away, wrapping around to the other side at the edges.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    pub fn step_wrapping(&self, position: Point<usize>, offset: Point<isize>) -> Point<usize> {
+        let wrap =
+            |n: usize, d: isize, len: usize| (n as i64 + d as i64).rem_euclid(len as i64) as usize;
+        Point::new(
+            wrap(position.x, offset.x, self.width),
+            wrap(position.y, offset.y, self.height),
+        )
+    }
+
+    /// The positions above, right of, below and left of a cell that are on the grid.
+    pub fn neighbours(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.offset()))
+    }
+
+    /// The positions of every cell touching a cell, including diagonally.
+    pub fn surrounding(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |offset| self.step(position, offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // Not `chunks`, which can't split rows with no columns
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        (0..self.height).map(move |y| &self[Point::new(x, y)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> + '_ {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::new(i % width, i / width), cell))
+    }
+
+    /// The position of the first cell equal to `value`, reading a row at a time.
+    pub fn find(&self, value: &T) -> Option<Point<usize>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|&(_, cell)| cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// A copy with the rows as columns and the columns as rows.
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(
+            width,
+            height,
+            iter::repeat_n(value, width * height).collect(),
+        )
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "Position {} should be within the {} by {} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!(
+                "Position {} should be within the {} by {} grid",
+                position, width, height
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Direction::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse(input, Ok).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_of_cells() {
+        let grid = grid("#..\n.S#\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), ['.', 'S', '#']);
+        assert_eq!(grid[Point::new(2, 0)], '.');
+        assert_eq!(grid.find(&'S'), Some(Point::new(1, 1)));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+
+        let empty = Grid::parse("", Ok).unwrap();
+        assert_eq!(
+            (empty.width(), empty.height(), empty.rows().count()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_cell() {
+        let input = "..\n.x\n";
+        let err = Grid::parse(input, |c| match c {
+            '.' => Ok(()),
+            _ => Err(format!("Unexpected '{}'", c)),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = Grid::parse("...\n..\n", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let input = "..\n\n.x";
+        let block = &input[4..];
+        let err = Grid::parse_within(input, block, |c| match c {
+            '.' => Ok(()),
+            _ => Err("Unexpected".to_string()),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+
+    #[test]
+    fn steps_stay_on_or_wrap_around_the_grid() {
+        let grid = grid("abc\ndef\n");
+        let origin = Point::new(0, 0);
+        assert_eq!(grid.step(origin, Point::new(1, 1)), Some(Point::new(1, 1)));
+        assert_eq!(grid.step(origin, West.offset()), None);
+        assert_eq!(grid.step(Point::new(2, 1), South.offset()), None);
+        assert_eq!(grid.step_wrapping(origin, West.offset()), Point::new(2, 0));
+        assert_eq!(
+            grid.step_wrapping(Point::new(2, 1), Point::new(0, 3)),
+            Point::new(2, 0)
+        );
+        assert_eq!(*grid.get_wrapping(Point::new(-1, -1)), 'f');
+        assert_eq!(*grid.get_wrapping(Point::new(7, 4)), 'b');
+
+        let neighbours: Vec<_> = grid.neighbours(origin).collect();
+        assert_eq!(neighbours, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.surrounding(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid("abc\ndef\n");
+        let column: String = grid.column(1).collect();
+        assert_eq!(column, "be");
+        let rows: Vec<String> = grid.rows().rev().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["def", "abc"]);
+
+        let transposed = grid.transposed();
+        assert_eq!((transposed.width(), transposed.height()), (2, 3));
+        assert_eq!(transposed.row(2), ['c', 'f']);
+        assert_eq!(transposed.transposed(), grid);
+    }
+}
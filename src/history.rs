@@ -0,0 +1,418 @@
+use std::{
+    env,
+    fmt::{Display, Error, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    json::{self, object, Value},
+    Answer, Part,
+};
+
+/// Environment variable used to override the default history directory.
+pub const HISTORY_DIR_VAR: &str = "AOC_HISTORY_DIR";
+
+/// What the server said about an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Also allow 'too-high', so verdicts can be given on the command line unquoted
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" | "too-high" => Ok(Verdict::TooHigh),
+            "too low" | "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "Verdict should be 'correct', 'too high', 'too low' or 'wrong', found '{}'",
+                s
+            )),
+        }
+    }
+}
+
+/// What's already known about an answer, without having to submit it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Known {
+    /// The answer has been tried before.
+    Tried(Verdict),
+    /// A different answer was accepted.
+    Solved(Answer),
+    /// The answer is at least as high as one that was too high.
+    AboveBound(i128),
+    /// The answer is at most as low as one that was too low.
+    BelowBound(i128),
+}
+
+impl Known {
+    /// The verdict the server would give.
+    pub fn verdict(&self) -> Verdict {
+        match self {
+            Known::Tried(verdict) => *verdict,
+            Known::Solved(_) => Verdict::Wrong,
+            Known::AboveBound(_) => Verdict::TooHigh,
+            Known::BelowBound(_) => Verdict::TooLow,
+        }
+    }
+}
+
+impl Display for Known {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Known::Tried(verdict) => write!(f, "already tried and was {}", verdict),
+            Known::Solved(answer) => write!(f, "wrong, the accepted answer is {}", answer),
+            Known::AboveBound(bound) => write!(f, "too high, {} was already too high", bound),
+            Known::BelowBound(bound) => write!(f, "too low, {} was already too low", bound),
+        }
+    }
+}
+
+/// An answer that was submitted for a part, and what the server said about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+/// Every answer tried for a day, so that none are ever sent twice.
+///
+/// Stored as JSON in `history/<year>/dayNN.json`:
+///
+/// ```json
+/// {
+///   "attempts": [
+///     { "part": 1, "answer": 54300, "verdict": "too high" },
+///     { "part": 1, "answer": 54239, "verdict": "correct" }
+///   ],
+///   "retry_after": 1701406800
+/// }
+/// ```
+///
+/// `retry_after` is when the server will next accept an answer, in seconds since the
+/// Unix epoch, and is left out once it has passed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+    pub retry_after: Option<SystemTime>,
+}
+
+#[derive(Debug)]
+pub struct HistoryError {
+    pub path: Option<PathBuf>,
+    pub reason: String,
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", path.display(), self.reason),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+/// The directory holding the history, `history/` unless overridden by [`HISTORY_DIR_VAR`].
+pub fn history_dir() -> PathBuf {
+    env::var_os(HISTORY_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("history"))
+}
+
+/// The location of the history for a given day.
+pub fn history_path(year: u16, day: u8) -> PathBuf {
+    history_dir()
+        .join(year.to_string())
+        .join(format!("day{:02}.json", day))
+}
+
+impl History {
+    /// Load a history, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        let err = |reason: String| HistoryError {
+            path: Some(path.to_path_buf()),
+            reason,
+        };
+        match fs::read_to_string(path) {
+            Ok(src) => Self::parse(&src).map_err(|e| err(e.reason)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(err(e.to_string())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), HistoryError> {
+        let err = |e: io::Error| HistoryError {
+            path: Some(path.to_path_buf()),
+            reason: e.to_string(),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(err)?;
+        }
+        fs::write(path, self.to_json().pretty() + "\n").map_err(err)
+    }
+
+    pub fn parse(src: &str) -> Result<Self, HistoryError> {
+        let err = |reason: String| HistoryError { path: None, reason };
+
+        let value = json::parse(src).map_err(|e| err(e.to_string()))?;
+        let attempts = value
+            .get("attempts")
+            .and_then(Value::as_array)
+            .ok_or_else(|| err("Expected an object with an 'attempts' array".to_string()))?;
+
+        let mut history = Self::default();
+        for (i, attempt) in attempts.iter().enumerate() {
+            let err = |reason: String| err(format!("Attempt {}: {}", i + 1, reason));
+            let field = |key: &str| {
+                attempt
+                    .get(key)
+                    .ok_or_else(|| err(format!("Missing '{}'", key)))
+            };
+
+            let part = field("part")?
+                .as_int()
+                .ok_or_else(|| err("Expected 'part' to be a number".to_string()))?;
+            let part: Part = part.to_string().parse().map_err(err)?;
+            let answer = Answer::try_from(field("answer")?).map_err(err)?;
+            let verdict = field("verdict")?
+                .as_str()
+                .ok_or_else(|| err("Expected 'verdict' to be a string".to_string()))?
+                .parse()
+                .map_err(err)?;
+            history.attempts.push(Attempt {
+                part,
+                answer,
+                verdict,
+            });
+        }
+
+        history.retry_after = match value.get("retry_after") {
+            None | Some(Value::Null) => None,
+            Some(secs) => {
+                let secs = secs
+                    .as_int()
+                    .and_then(|secs| u64::try_from(secs).ok())
+                    .ok_or_else(|| err("Expected 'retry_after' to be a time".to_string()))?;
+                Some(UNIX_EPOCH + Duration::from_secs(secs))
+            }
+        };
+
+        Ok(history)
+    }
+
+    pub fn to_json(&self) -> Value {
+        let attempts = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                object! {
+                    "part" => attempt.part.number(),
+                    "answer" => &attempt.answer,
+                    "verdict" => attempt.verdict.as_str(),
+                }
+            })
+            .collect();
+        let retry_after = self
+            .retry_after
+            .filter(|&time| time > SystemTime::now())
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|since| since.as_secs());
+
+        let mut value = object! { "attempts" => Value::Array(attempts) };
+        if let (Value::Object(members), Some(secs)) = (&mut value, retry_after) {
+            members.push(("retry_after".to_string(), secs.into()));
+        }
+        value
+    }
+
+    /// The verdict the server would give an answer, if it can be worked out from earlier
+    /// attempts.
+    pub fn verdict(&self, part: Part, answer: &Answer) -> Option<Verdict> {
+        self.known(part, answer).map(|known| known.verdict())
+    }
+
+    /// What's known about an answer from earlier attempts.
+    ///
+    /// Once a part is solved every other answer is known to be wrong, and before then a
+    /// number is known to be wrong if it's past an answer that was too high or too low.
+    pub fn known(&self, part: Part, answer: &Answer) -> Option<Known> {
+        if let Some(attempt) = self
+            .attempts
+            .iter()
+            .find(|a| a.part == part && &a.answer == answer)
+        {
+            return Some(Known::Tried(attempt.verdict));
+        }
+        if let Some(correct) = self.correct(part) {
+            return Some(Known::Solved(correct.clone()));
+        }
+
+        let Answer::Int(n) = *answer else {
+            return None;
+        };
+        let (low, high) = self.bounds(part);
+        match (low, high) {
+            (_, Some(high)) if n >= high => Some(Known::AboveBound(high)),
+            (Some(low), _) if n <= low => Some(Known::BelowBound(low)),
+            _ => None,
+        }
+    }
+
+    /// The highest answer that was too low and the lowest that was too high.
+    pub fn bounds(&self, part: Part) -> (Option<i128>, Option<i128>) {
+        let mut low = None;
+        let mut high = None;
+        for attempt in self.attempts.iter().filter(|a| a.part == part) {
+            match (&attempt.answer, attempt.verdict) {
+                (&Answer::Int(n), Verdict::TooLow) => low = low.max(Some(n)),
+                (&Answer::Int(n), Verdict::TooHigh) => {
+                    high = Some(high.map_or(n, |high: i128| high.min(n)))
+                }
+                _ => (),
+            }
+        }
+        (low, high)
+    }
+
+    /// The answer the server accepted for a part.
+    pub fn correct(&self, part: Part) -> Option<&Answer> {
+        self.attempts
+            .iter()
+            .find(|a| a.part == part && a.verdict == Verdict::Correct)
+            .map(|a| &a.answer)
+    }
+
+    /// Record the verdict on an answer, replacing any earlier one.
+    pub fn record(&mut self, part: Part, answer: Answer, verdict: Verdict) {
+        self.attempts
+            .retain(|a| !(a.part == part && a.answer == answer));
+        self.attempts.push(Attempt {
+            part,
+            answer,
+            verdict,
+        });
+    }
+
+    /// How long until the server will accept another answer, if it's still waiting.
+    pub fn wait(&self, now: SystemTime) -> Option<Duration> {
+        self.retry_after
+            .and_then(|time| time.duration_since(now).ok())
+            .filter(|wait| !wait.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut history = History::default();
+        history.record(Part::One, Answer::Int(54300), Verdict::TooHigh);
+        history.record(Part::One, Answer::Int(54239), Verdict::Correct);
+        history.record(
+            Part::Two,
+            Answer::Lines(vec!["#.".to_string(), ".#".to_string()]),
+            Verdict::Wrong,
+        );
+        history.retry_after = Some(UNIX_EPOCH + Duration::from_secs(4_000_000_000));
+
+        let parsed = History::parse(&history.to_json().pretty()).unwrap();
+        assert_eq!(parsed, history);
+    }
+
+    #[test]
+    fn knows_verdicts_of_earlier_answers() {
+        let mut history = History::default();
+        history.record(Part::One, Answer::Int(100), Verdict::TooHigh);
+        assert_eq!(
+            history.verdict(Part::One, &Answer::Int(100)),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(history.verdict(Part::One, &Answer::Int(90)), None);
+        assert_eq!(history.verdict(Part::Two, &Answer::Int(100)), None);
+
+        history.record(Part::One, Answer::Int(90), Verdict::Correct);
+        assert_eq!(history.correct(Part::One), Some(&Answer::Int(90)));
+        assert_eq!(
+            history.verdict(Part::One, &Answer::Int(80)),
+            Some(Verdict::Wrong)
+        );
+    }
+
+    #[test]
+    fn infers_verdicts_from_bounds() {
+        let mut history = History::default();
+        history.record(Part::One, Answer::Int(500), Verdict::TooHigh);
+        history.record(Part::One, Answer::Int(800), Verdict::TooHigh);
+        history.record(Part::One, Answer::Int(100), Verdict::TooLow);
+        history.record(Part::One, Answer::Int(300), Verdict::Wrong);
+        assert_eq!(history.bounds(Part::One), (Some(100), Some(500)));
+        assert_eq!(history.bounds(Part::Two), (None, None));
+
+        let known = |n| history.known(Part::One, &Answer::Int(n));
+        assert_eq!(known(600), Some(Known::AboveBound(500)));
+        assert_eq!(known(500), Some(Known::Tried(Verdict::TooHigh)));
+        assert_eq!(known(50), Some(Known::BelowBound(100)));
+        assert_eq!(known(300), Some(Known::Tried(Verdict::Wrong)));
+        assert_eq!(known(250), None);
+        assert_eq!(history.known(Part::Two, &Answer::Int(600)), None);
+        assert_eq!(history.known(Part::One, &Answer::from("abc")), None);
+    }
+
+    #[test]
+    fn drops_waits_that_have_passed() {
+        let now = SystemTime::now();
+        let history = History {
+            retry_after: Some(now - Duration::from_secs(1)),
+            ..History::default()
+        };
+        assert_eq!(history.wait(now), None);
+        assert_eq!(history.to_json().get("retry_after"), None);
+
+        let history = History {
+            retry_after: Some(now + Duration::from_secs(30)),
+            ..History::default()
+        };
+        assert_eq!(history.wait(now), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn rejects_invalid_histories() {
+        assert!(History::parse("{}").is_err());
+        let err = History::parse(r#"{"attempts": [{"part": 1, "answer": 5, "verdict": "close"}]}"#)
+            .unwrap_err();
+        assert!(err.reason.starts_with("Attempt 1:"), "{}", err);
+    }
+}
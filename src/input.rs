@@ -0,0 +1,218 @@
+use std::{
+    borrow::Cow,
+    env,
+    fmt::{Display, Error, Formatter},
+    fs, io,
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable used to override the default input directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read a puzzle's input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The day's file inside the input directory, see [`input_dir`].
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    /// The file that couldn't be read, or `None` for stdin.
+    pub path: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match (&self.path, self.source.kind()) {
+            (Some(path), io::ErrorKind::NotFound) => {
+                write!(f, "Input file '{}' doesn't exist", path.display())
+            }
+            (Some(path), _) => write!(f, "Failed to read '{}': {}", path.display(), self.source),
+            (None, _) => write!(f, "Failed to read input from stdin: {}", self.source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The directory holding the puzzle inputs, `input/` unless overridden by [`INPUT_DIR_VAR`].
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("input"))
+}
+
+/// The default location of the input for a given day, `<input dir>/<year>/dayNN.txt`.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    day_path(&input_dir(), year, day)
+}
+
+/// The location of the input for a given day within an input directory.
+pub fn day_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+impl Source {
+    /// Resolve the path that will be read, if reading from a file.
+    pub fn path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::Default => Some(default_path(year, day)),
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.path(year, day) {
+            Some(path) => fs::read_to_string(&path).map_err(|source| InputError {
+                path: Some(path),
+                source,
+            }),
+            None => read_stdin(),
+        }
+    }
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| InputError { path: None, source })?;
+    Ok(input)
+}
+
+/// Input piped in on stdin, or `None` if stdin is a terminal or only whitespace was piped in,
+/// e.g. by a script that doesn't mean to give an input.
+pub fn piped() -> Result<Option<String>, InputError> {
+    if io::stdin().is_terminal() {
+        return Ok(None);
+    }
+    Ok(Some(read_stdin()?).filter(|input| !input.trim().is_empty()))
+}
+
+/// Convert Windows line endings to `\n`, borrowing the input when there are none.
+///
+/// The registry does this before handing an input to a day, so days only see `\n`.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    match input.contains('\r') {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    }
+}
+
+/// Split an input into the blocks separated by blank lines, with either line ending.
+///
+/// Leading, trailing and repeated blank lines don't produce empty blocks, and each block
+/// is a slice of `input` without its final line ending.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks { rest: input }
+}
+
+/// Iterator over blank-line separated blocks, see [`blocks`].
+#[derive(Debug, Clone)]
+pub struct Blocks<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Blocks<'a> {
+    /// Split off the first line of the rest of the input, including its line ending.
+    fn next_line(&self, from: usize) -> (&'a str, usize) {
+        let end = self.rest[from..]
+            .find('\n')
+            .map_or(self.rest.len(), |i| from + i + 1);
+        (&self.rest[from..end], end)
+    }
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip any blank lines before the block
+        let mut start = 0;
+        while start < self.rest.len() {
+            let (line, end) = self.next_line(start);
+            if !line.trim().is_empty() {
+                break;
+            }
+            start = end;
+        }
+
+        // The block runs until the next blank line
+        let mut end = start;
+        while end < self.rest.len() {
+            let (line, next) = self.next_line(end);
+            if line.trim().is_empty() {
+                break;
+            }
+            end = next;
+        }
+
+        let block = self.rest[start..end].trim_end_matches(['\r', '\n']);
+        self.rest = &self.rest[end..];
+        (!block.is_empty()).then_some(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    #[test]
+    fn inputs_are_namespaced_by_year() {
+        let path = Source::Default.path(2023, 5).unwrap();
+        assert!(path.ends_with("2023/day05.txt"), "{}", path.display());
+        assert_eq!(Source::Stdin.path(2023, 5), None);
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+    }
+
+    #[test]
+    fn splits_blocks_with_either_line_ending() {
+        let lf = "seeds: 1 2\n\nmap:\n1 2 3\n4 5 6\n";
+        let crlf = lf.replace('\n', "\r\n");
+
+        assert_eq!(
+            blocks(lf).collect::<Vec<_>>(),
+            ["seeds: 1 2", "map:\n1 2 3\n4 5 6"]
+        );
+        assert_eq!(
+            blocks(&crlf).collect::<Vec<_>>(),
+            ["seeds: 1 2", "map:\r\n1 2 3\r\n4 5 6"]
+        );
+    }
+
+    #[test]
+    fn skips_extra_blank_lines() {
+        assert_eq!(
+            blocks("\n\na\n\n\n\nb\nc\n\n").collect::<Vec<_>>(),
+            ["a", "b\nc"]
+        );
+        assert_eq!(blocks("a\n  \nb").collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(blocks("").next(), None);
+        assert_eq!(blocks("\r\n\r\n").next(), None);
+    }
+
+    #[test]
+    fn blocks_borrow_from_the_input() {
+        let input = "#.\n.#\n\n##\n..";
+        let second = blocks(input).nth(1).unwrap();
+        let err = ParseError::at(input, second, "Second pattern");
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+}
@@ -0,0 +1,338 @@
+//! Ranges of integers, for puzzles that push whole ranges of values through a process
+//! instead of one value at a time.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Mul, Sub},
+};
+
+/// The integers from `start` up to but not including `end`, empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals share a value.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.start.max(other.start),
+            self.end.min(other.end),
+        ))
+        .filter(|i| !i.is_empty())
+    }
+
+    /// The values below `at` and the values from `at` up, either of which might be empty.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        let below = Self::new(self.start, at);
+        let above = Self::new(at, self.end);
+        (
+            Some(below).filter(|i| !i.is_empty()),
+            Some(above).filter(|i| !i.is_empty()),
+        )
+    }
+
+    /// The values below `other` and the values above it.
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if other.is_empty() {
+            return (Some(*self).filter(|i| !i.is_empty()), None);
+        }
+        let (below, _) = self.split_at(other.start);
+        let (_, above) = self.split_at(other.end);
+        (below, above)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default> Interval<T> {
+    /// The number of values in the interval.
+    pub fn len(&self) -> T {
+        match self.is_empty() {
+            true => T::default(),
+            false => self.end - self.start,
+        }
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Any set of integers, kept as the fewest sorted intervals that cover it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Sorted, non-empty, and with gaps between each interval and the next.
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// The intervals making up the set, from lowest to highest.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> + '_ {
+        self.intervals.iter()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // Merge with every interval that overlaps or touches the new one
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, i| {
+                Interval::new(merged.start.min(i.start), merged.end.max(i.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intersection.extend(a.intersection(b));
+            // Whichever ends first can't overlap anything else in the other set
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            // Skip the intervals entirely below this one
+            while other
+                .intervals
+                .get(j)
+                .is_some_and(|i| i.end <= interval.start)
+            {
+                j += 1;
+            }
+            for removed in other.intervals[j..]
+                .iter()
+                .take_while(|i| i.start < interval.end)
+            {
+                let Some(remaining) = rest else {
+                    break;
+                };
+                let (below, above) = remaining.difference(removed);
+                difference.extend(below);
+                rest = above;
+            }
+            difference.extend(rest);
+        }
+        Self {
+            intervals: difference,
+        }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default + std::iter::Sum> IntervalSet<T> {
+    /// The number of values in the set.
+    pub fn size(&self) -> T {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+/// A box in `N` dimensions, made of an interval along each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> Cuboid<T, N> {
+    pub const fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, value)| axis.contains(value))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes) {
+            *axis = axis.intersection(&other)?;
+        }
+        Some(Self::new(axes))
+    }
+
+    /// The part of the box below `at` along an axis, and the part from `at` up.
+    pub fn split_at(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let with_axis = |interval: Interval<T>| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Self::new(axes)
+        };
+        (below.map(with_axis), above.map(with_axis))
+    }
+}
+
+impl<T, const N: usize> Cuboid<T, N>
+where
+    T: Copy + Ord + Sub<Output = T> + Mul<Output = T> + Default + From<u8>,
+{
+    /// The number of points in the box.
+    pub fn volume(&self) -> T {
+        self.axes
+            .iter()
+            .fold(T::from(1), |volume, axis| volume * axis.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn intervals_split_and_intersect() {
+        let interval = Interval::new(10, 20);
+        assert_eq!(interval.len(), 10);
+        assert!(interval.contains(10) && !interval.contains(20));
+        assert_eq!(
+            interval.split_at(15),
+            (Some(Interval::new(10, 15)), Some(Interval::new(15, 20)))
+        );
+        assert_eq!(interval.split_at(5), (None, Some(interval)));
+        assert_eq!(interval.split_at(25), (Some(interval), None));
+        assert_eq!(
+            interval.intersection(&Interval::new(18, 30)),
+            Some(Interval::new(18, 20))
+        );
+        assert!(!interval.overlaps(&Interval::new(20, 30)));
+        assert_eq!(
+            interval.difference(&Interval::new(12, 14)),
+            (Some(Interval::new(10, 12)), Some(Interval::new(14, 20)))
+        );
+        assert_eq!(Interval::new(5, 3).len(), 0);
+    }
+
+    #[test]
+    fn sets_merge_overlapping_and_touching_intervals() {
+        let merged = set(&[(5, 8), (1, 3), (3, 4), (7, 10), (12, 12)]);
+        assert_eq!(merged, set(&[(1, 4), (5, 10)]));
+        assert_eq!(merged.size(), 8);
+        assert_eq!(merged.min(), Some(1));
+        assert!(merged.contains(9) && !merged.contains(4));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+        assert!(a.intersection(&set(&[(10, 20)])).is_empty());
+    }
+
+    #[test]
+    fn cuboids_split_along_an_axis() {
+        let cuboid = Cuboid::new([Interval::new(1u64, 11), Interval::new(0, 5)]);
+        assert_eq!(cuboid.volume(), 50);
+        let (below, above) = cuboid.split_at(0, 4);
+        assert_eq!(below.map(|c| c.volume()), Some(15));
+        assert_eq!(above.map(|c| c.volume()), Some(35));
+        assert!(cuboid.contains([10, 4]) && !cuboid.contains([11, 4]));
+        assert_eq!(cuboid.split_at(1, 0), (None, Some(cuboid)));
+
+        let other = Cuboid::new([Interval::new(5, 20), Interval::new(3, 9)]);
+        assert_eq!(
+            cuboid.intersection(&other),
+            Some(Cuboid::new([Interval::new(5, 11), Interval::new(3, 5)]))
+        );
+    }
+}
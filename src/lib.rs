@@ -1,4 +1,113 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod cli;
+pub mod client;
+mod error;
+pub mod examples;
+pub mod explore;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod log;
+pub mod math;
+mod params;
+pub mod point;
+pub mod pool;
+pub mod registry;
+pub mod scaffold;
+pub mod search;
+pub mod toml;
+pub mod watch;
+pub mod y2023;
+
+pub use answer::Answer;
+pub use error::{Error, ParseError};
+pub use params::Params;
+
 pub trait Day {
-    fn problem1();
-    fn problem2();
+    /// The year of the event the puzzle is from.
+    const YEAR: u16;
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// The puzzle input after parsing, shared by both parts.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+    fn problem1(parsed: &Self::Parsed<'_>) -> Result<Answer, Error>;
+    fn problem2(parsed: &Self::Parsed<'_>) -> Result<Answer, Error>;
+
+    /// Solve part one with some of the puzzle's constants overridden.
+    fn problem1_with(parsed: &Self::Parsed<'_>, _params: &Params) -> Result<Answer, Error> {
+        Self::problem1(parsed)
+    }
+
+    /// Solve part two with some of the puzzle's constants overridden.
+    fn problem2_with(parsed: &Self::Parsed<'_>, _params: &Params) -> Result<Answer, Error> {
+        Self::problem2(parsed)
+    }
+
+    /// Queries understood by [`Day::explore`], as `(usage, description)` pairs.
+    const QUERIES: &'static [(&'static str, &'static str)] = &[];
+
+    /// Answer a query typed into `explore`, already split into words.
+    ///
+    /// Queries can change the parsed input, e.g. to step a simulation, and later queries
+    /// see those changes.
+    fn explore(_parsed: &mut Self::Parsed<'_>, query: &[&str]) -> Result<String, String> {
+        Err(format!("Unknown query '{}'", query.join(" ")))
+    }
+
+    /// Solve either part of an already parsed input.
+    fn solve(parsed: &Self::Parsed<'_>, part: Part, params: &Params) -> Result<Answer, Error> {
+        match part {
+            Part::One => Self::problem1_with(parsed, params),
+            Part::Two => Self::problem2_with(parsed, params),
+        }
+    }
+}
+
+/// Each puzzle is made up of two parts, the second unlocked by solving the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Part should be 1 or 2, found '{}'", s)),
+        }
+    }
 }
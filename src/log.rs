@@ -0,0 +1,98 @@
+//! Diagnostics that days can emit about their intermediate state, kept out of the answers.
+//!
+//! Messages go to stderr, and only when the verbosity given with `-v` or `-vv` is high
+//! enough for their level:
+//!
+//! ```ignore
+//! log::info!("Cycle of {} is {} steps", start, length);
+//! log::debug!("Mapped {}..{} to {}..{}", start, end, mapped, mapped_end);
+//! ```
+
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// A summary of how a part was solved, shown with `-v`.
+    Info = 1,
+    /// Every step along the way, shown with `-vv`.
+    Debug = 2,
+}
+
+impl Level {
+    /// Whether messages at this level are shown at a verbosity.
+    pub fn shown_at(self, verbosity: u8) -> bool {
+        verbosity >= self as u8
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+        }
+    }
+}
+
+/// Show messages up to a level, from 0 for none to 2 for [`Level::Debug`].
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+pub fn enabled(level: Level) -> bool {
+    level.shown_at(verbosity())
+}
+
+/// Write a message from a module, as used by [`info!`] and [`debug!`].
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let module = module.strip_prefix("advent_of_code::").unwrap_or(module);
+    eprintln!("{} {}: {}", level, module, args);
+}
+
+/// Log a message at a level, only formatting it if it'll be shown.
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Log a summary of how a part was solved.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        $crate::log::log!($crate::log::Level::Info, $($arg)*)
+    };
+}
+
+/// Log a step along the way to solving a part.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log::log!($crate::log::Level::Debug, $($arg)*)
+    };
+}
+
+pub(crate) use {debug, info, log};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_need_enough_verbosity() {
+        assert!(!Level::Info.shown_at(0));
+        assert!(Level::Info.shown_at(1));
+        assert!(!Level::Debug.shown_at(1));
+        assert!(Level::Debug.shown_at(2));
+        assert!(Level::Info.shown_at(3));
+    }
+}
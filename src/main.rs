@@ -1,8 +1,5 @@
-use advent_of_code::Day;
+use std::process::ExitCode;
 
-pub mod days;
-
-fn main() {
-    days::Day21::problem1();
-    days::Day21::problem2();
+fn main() -> ExitCode {
+    advent_of_code::cli::main(std::env::args().skip(1))
 }
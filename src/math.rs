@@ -0,0 +1,191 @@
+//! Number theory for puzzles about cycles lining up.
+
+use std::ops::{Div, Mul, Rem};
+
+/// The integer types that [`gcd`] and [`lcm`] work with.
+pub trait Integer:
+    Copy + Ord + Div<Output = Self> + Mul<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value, panicking for the minimum of a signed type, which has none.
+    fn abs(self) -> Self;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    self
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::checked_abs(self).expect("Should have an absolute value that fits")
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor, which is never negative.
+///
+/// # Panics
+///
+/// If either value is the minimum of a signed type, as its absolute value doesn't fit.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, or `None` if it doesn't fit in `T`.
+///
+/// # Panics
+///
+/// If either value is the minimum of a signed type, like [`gcd`].
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// The least common multiple.
+///
+/// # Panics
+///
+/// If it doesn't fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("Should be an LCM that fits in the integer type")
+}
+
+/// The least common multiple of every value, 1 if there are none, or `None` if it doesn't
+/// fit in `T`.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| checked_lcm(acc, value))
+}
+
+/// The GCD `g` of `a` and `b`, along with `x` and `y` such that `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// The `x` in `0..modulus` where `a * x` is 1 modulo `modulus`, or `None` if `a` and
+/// `modulus` share a factor.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a, modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// The smallest non-negative `x` that leaves every `(remainder, modulus)` pair's remainder
+/// when divided by its modulus, along with the LCM of the moduli that the solutions repeat
+/// after.
+///
+/// The moduli don't need to be coprime. `None` if no `x` works for every pair, but also if
+/// the LCM, or a product worked out on the way to it, doesn't fit in an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, modulus), (remainder, other)| {
+            // Any x + k * modulus keeps the earlier pairs, so solve for k with this one
+            let (g, inverse, _) = extended_gcd(modulus, other);
+            let difference = remainder - x;
+            if difference % g != 0 {
+                return None;
+            }
+            let step = other / g;
+            let k = (difference / g % step)
+                .checked_mul(inverse % step)?
+                .rem_euclid(step);
+            let combined = modulus.checked_mul(step)?;
+            Some((
+                x.checked_add(modulus.checked_mul(k)?)?.rem_euclid(combined),
+                combined,
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(checked_lcm(u8::MAX, 2), None);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u32>::new()), Some(1));
+        assert_eq!(lcm_all([1 << 40, 3u64 << 30]), Some(3 << 40));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+        assert_eq!(gcd(i64::MIN + 1, 3), 1);
+    }
+
+    #[test]
+    fn extended_euclid_and_inverses() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 10), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli with common factors
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        // Too big to work out, rather than no solution
+        assert_eq!(crt([(0, i128::MAX), (1, i128::MAX - 1)]), None);
+    }
+}
@@ -0,0 +1,51 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::Error;
+
+/// Overrides for the constants a puzzle uses, such as the number of steps to take.
+///
+/// The worked examples often use smaller values than the real puzzle, so days that
+/// depend on them read them from here with the real value as the default.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl ToString) {
+        self.values.insert(key.into(), value.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Get a parameter, or `default` if it hasn't been overridden.
+    pub fn get_or<T>(&self, key: &str, default: T) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.values.get(key) {
+            Some(value) => value.parse().map_err(|e: T::Err| Error::Param {
+                key: key.to_string(),
+                value: value.clone(),
+                reason: e.to_string(),
+            }),
+            None => Ok(default),
+        }
+    }
+}
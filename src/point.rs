@@ -0,0 +1,262 @@
+//! Positions and compass directions on a 2D plane.
+//!
+//! `x` grows to the east and `y` grows to the south, to match a grid read a row at a time.
+//! Signed points can go anywhere, while unsigned points are positions on a grid and only
+//! move with checked arithmetic.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use self::Direction::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [North, East, South, West];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            North => West,
+            East => North,
+            South => East,
+            West => South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            North => South,
+            East => West,
+            South => North,
+            West => East,
+        }
+    }
+
+    /// The change in position from taking one step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        };
+        Point::new(T::from(x), T::from(y))
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            North => write!(f, "north"),
+            East => write!(f, "east"),
+            South => write!(f, "south"),
+            West => write!(f, "west"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid direction '{}', should be one of U/R/D/L, N/E/S/W or ^/>/v/<",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | 'N' | '^' => Ok(North),
+            'R' | 'E' | '>' => Ok(East),
+            'D' | 'S' | 'v' => Ok(South),
+            'L' | 'W' | '<' => Ok(West),
+            _ => Err(ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into().map_err(|_| ParseDirectionError(s.to_string())),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + From<i8> + Add<Output = T> + Mul<Output = T>,
+{
+    /// The point `n` steps away in a direction.
+    pub fn step(self, direction: Direction, n: T) -> Self {
+        self + direction.offset() * n
+    }
+}
+
+impl Point<i64> {
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Point<usize> {
+    /// The position `offset` away, or `None` if that would be left of or above the origin.
+    pub fn checked_add_signed(self, offset: Point<isize>) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// The position one step away in a direction, or `None` if that would be left of or
+    /// above the origin.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        self.checked_add_signed(direction.offset())
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_reverses() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.offset::<i64>() + direction.reverse().offset(),
+                Point::new(0, 0)
+            );
+        }
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+    }
+
+    #[test]
+    fn parses_letters_and_arrows() {
+        let parsed: Vec<Direction> = ["U", "R", "D", "L", "N", "E", "S", "W", "^", ">", "v", "<"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(parsed, [Direction::ALL; 3].concat());
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UR".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn moves_signed_and_unsigned_points() {
+        let point = Point::new(2i64, 3);
+        assert_eq!(point.step(North, 5), Point::new(2, -2));
+        assert_eq!(point - Point::new(1, 1), Point::new(1, 2));
+        assert_eq!(point.manhattan(Point::new(-1, 0)), 6);
+
+        let position = Point::new(0usize, 1);
+        assert_eq!(position.checked_step(South), Some(Point::new(0, 2)));
+        assert_eq!(position.checked_step(West), None);
+        assert_eq!(
+            position.checked_add_signed(Point::new(3, -1)),
+            Some(Point::new(3, 0))
+        );
+    }
+}
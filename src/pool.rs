@@ -0,0 +1,89 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// The number of jobs to run at once when none is given, one per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Apply `f` to every item on up to `jobs` threads, returning the results in the same
+/// order as the items.
+///
+/// Threads take the next item as soon as they finish their last, so a few slow items
+/// don't hold up the rest. A panic in `f` is passed on once every thread has stopped.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                // The receiver outlives the scope, so sending can't fail
+                let _ = sender.send((i, f(item)));
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results: Vec<Option<R>> = (0..items.len()).map(|_| None).collect();
+    for (i, result) in receiver {
+        results[i] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("Should have a result for every item"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashSet, sync::Mutex, time::Duration};
+
+    #[test]
+    fn keeps_results_in_order() {
+        let items: Vec<u64> = (0..50).collect();
+        let results = map(&items, 4, |&n| {
+            // Finish out of order
+            thread::sleep(Duration::from_micros((50 - n) * 20));
+            n * n
+        });
+        assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn runs_on_several_threads() {
+        let threads = Mutex::new(HashSet::new());
+        map(&[(); 8], 4, |_| {
+            threads
+                .lock()
+                .expect("Thread set shouldn't be poisoned")
+                .insert(thread::current().id());
+            thread::sleep(Duration::from_millis(20));
+        });
+        assert!(threads.into_inner().unwrap().len() > 1);
+    }
+
+    #[test]
+    fn handles_no_items_and_no_jobs() {
+        assert_eq!(map(&[] as &[u8], 4, |&n| n), Vec::<u8>::new());
+        assert_eq!(map(&[1, 2, 3], 0, |&n| n + 1), vec![2, 3, 4]);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{any::Any, collections::BTreeMap, panic};
+
+use crate::{
+    bench::{self, BenchConfig, Timing},
+    explore::{self, Session},
+    input, Answer, Day, Error, Params, Part,
+};
+
+/// Parse an input and solve a single part of a puzzle.
+pub type Solution = fn(Part, &str, &Params) -> Result<Answer, Error>;
+
+/// Repeatedly parse an input and solve a single part, timing each stage.
+pub type Benchmark = fn(Part, &str, &Params, &BenchConfig) -> Result<Timing, Error>;
+
+/// Parse an input and answer queries about it until the session ends.
+pub type Exploration = fn(&str, &mut dyn Session) -> Result<(), Error>;
+
+/// The last day of each event.
+pub const LAST_DAY: u8 = 25;
+
+/// A solved day, with its parts erased to plain function pointers.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    /// Queries that can be asked with `explore`, see [`Day::QUERIES`].
+    pub queries: &'static [(&'static str, &'static str)],
+    solution: Solution,
+    benchmark: Benchmark,
+    exploration: Exploration,
+}
+
+fn solve<D: Day>(part: Part, input: &str, params: &Params) -> Result<Answer, Error> {
+    D::solve(&D::parse(input)?, part, params)
+}
+
+impl Entry {
+    pub const fn new<D: Day>(name: &'static str) -> Self {
+        Self {
+            year: D::YEAR,
+            day: D::DAY,
+            name,
+            queries: D::QUERIES,
+            solution: solve::<D>,
+            benchmark: bench::run::<D>,
+            exploration: explore::run::<D>,
+        }
+    }
+
+    /// Solve a part, after converting the input to `\n` line endings.
+    pub fn solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer, Error> {
+        (self.solution)(part, &input::normalize(input), params).map_err(|e| e.in_day(self.day))
+    }
+
+    pub fn bench(
+        &self,
+        part: Part,
+        input: &str,
+        params: &Params,
+        config: &BenchConfig,
+    ) -> Result<Timing, Error> {
+        (self.benchmark)(part, &input::normalize(input), params, config)
+            .map_err(|e| e.in_day(self.day))
+    }
+
+    /// Parse an input and answer queries about it, see [`explore`].
+    pub fn explore(&self, input: &str, session: &mut dyn Session) -> Result<(), Error> {
+        (self.exploration)(&input::normalize(input), session).map_err(|e| e.in_day(self.day))
+    }
+
+    /// Solve a part, catching a panic and returning it as an error instead.
+    pub fn try_solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer, Error> {
+        panic::catch_unwind(|| self.solve(part, input, params)).unwrap_or_else(|payload| {
+            Err(Error::Panic {
+                day: Some(self.day),
+                message: panic_message(payload),
+            })
+        })
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Panicked".to_string(),
+        },
+    }
+}
+
+/// Add each day of a year to the registry.
+///
+/// Each line is `<module>::<type>`, where the module is declared next to the macro so that
+/// rustfmt can find it, and the type must implement [`Day`], which gives the year and day
+/// it's registered under.
+macro_rules! register_days {
+    ($($module:ident::$name:ident),* $(,)?) => {
+        $(
+            pub use $module::$name;
+        )*
+
+        /// Every day registered for this year, in the order they were declared.
+        pub const REGISTERED: &[$crate::registry::Entry] = &[
+            $($crate::registry::Entry::new::<$name>(stringify!($name)),)*
+        ];
+    };
+}
+
+pub(crate) use register_days;
+
+/// The days registered by each year's module, e.g. `src/y2023.rs`.
+///
+/// `new` adds to this list when it scaffolds the first day of a year.
+const YEARS: &[&[Entry]] = &[crate::y2023::REGISTERED];
+
+/// Every registered day, in the order they were declared.
+pub fn all() -> impl Iterator<Item = &'static Entry> {
+    YEARS.iter().flat_map(|entries| entries.iter())
+}
+
+/// Find the entry for a single day.
+///
+/// If a day has been registered more than once the first is used, see [`duplicates`].
+pub fn get(year: u16, day: u8) -> Option<&'static Entry> {
+    all().find(|entry| entry.year == year && entry.day == day)
+}
+
+/// Every registered entry for a year, ordered by day.
+pub fn year(year: u16) -> Vec<&'static Entry> {
+    let mut entries: Vec<_> = all().filter(|entry| entry.year == year).collect();
+    entries.sort_by_key(|entry| entry.day);
+    entries.dedup_by_key(|entry| entry.day);
+    entries
+}
+
+/// Years with at least one registered day, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = all().map(|entry| entry.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// The most recent year with a registered day.
+pub fn latest_year() -> Option<u16> {
+    years().last().copied()
+}
+
+/// Days of the event that have no entry for a year.
+pub fn missing(year: u16) -> Vec<u8> {
+    (1..=LAST_DAY)
+        .filter(|&day| get(year, day).is_none())
+        .collect()
+}
+
+/// Days that have been registered more than once, with the names of each implementation.
+pub fn duplicates() -> Vec<((u16, u8), Vec<&'static str>)> {
+    let mut seen: BTreeMap<(u16, u8), Vec<&'static str>> = BTreeMap::new();
+    for entry in all() {
+        seen.entry((entry.year, entry.day))
+            .or_default()
+            .push(entry.name);
+    }
+    seen.into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .collect()
+}
+
+/// Entries whose day falls outside of the event.
+pub fn invalid() -> Vec<&'static Entry> {
+    all()
+        .filter(|entry| entry.day == 0 || entry.day > LAST_DAY)
+        .collect()
+}
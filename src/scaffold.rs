@@ -0,0 +1,450 @@
+use std::{
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{examples, input, registry::LAST_DAY};
+
+/// The module generated for a new day, with `{year}`, `{day}`, `{example}` and `{name}` filled
+/// in.
+const DAY_TEMPLATE: &str = "\
+use crate::{Answer, Day, Error, ParseError};
+
+pub struct {name} {}
+
+impl Day for {name} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn problem1(_lines: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Err(Error::solve(\"Part 1 hasn't been solved yet\"))
+    }
+
+    fn problem2(_lines: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Err(Error::solve(\"Part 2 hasn't been solved yet\"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!({example});
+
+    #[test]
+    fn parses_example() {
+        assert!({name}::parse(EXAMPLE).is_ok());
+    }
+}
+";
+
+/// The module for a year with no days, which days are then registered in.
+const YEAR_TEMPLATE: &str = "\
+use crate::registry::register_days;
+
+register_days! {
+}
+";
+
+/// The expected answers for the example, to be filled in from the puzzle description.
+const EXPECTED_TEMPLATE: &str = "\
+# Copy the example from the puzzle into example.txt, then uncomment this table and
+# fill in its answers
+# [example]
+# part1 =
+# part2 =
+";
+
+/// Start of the list of year modules in `src/registry.rs`.
+const YEARS_START: &str = "const YEARS: &[&[Entry]] = &[";
+
+#[derive(Debug)]
+pub struct ScaffoldError {
+    pub path: Option<PathBuf>,
+    pub reason: String,
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", path.display(), self.reason),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// The files written when scaffolding a day, relative to the root it was given.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+fn type_name(day: u8) -> String {
+    format!("Day{:02}", day)
+}
+
+fn module_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// The module holding a day's solution, `src/y<year>/dayNN.rs`.
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    Path::new("src")
+        .join(format!("y{}", year))
+        .join(format!("{}.rs", module_name(day)))
+}
+
+/// The module registering a year's days, `src/y<year>.rs`.
+pub fn year_path(year: u16) -> PathBuf {
+    Path::new("src").join(format!("y{}.rs", year))
+}
+
+/// Declare a day's module in a year module and add it to the `register_days!` list, keeping
+/// the days in order.
+fn register_day(src: &str, day: u8) -> Result<String, String> {
+    let entry = format!("{}::{}", module_name(day), type_name(day));
+    let start = src
+        .find("register_days! {")
+        .ok_or("Should contain a 'register_days!' list")?;
+    let end = start
+        + src[start..]
+            .find("\n}")
+            .ok_or("The 'register_days!' list should end with '}'")?;
+
+    // Insert before the first day that comes later, or at the end of the list
+    let mut insert_at = end + 1;
+    let mut offset = src[..start].len();
+    for line in src[start..end].split_inclusive('\n') {
+        let registered = line.trim().trim_end_matches(',');
+        if registered == entry {
+            return Err(format!("Day {} is already registered", day));
+        }
+        if registered.starts_with("day") && registered > entry.as_str() {
+            insert_at = offset;
+            break;
+        }
+        offset += line.len();
+    }
+
+    let mut src = src.to_string();
+    src.insert_str(insert_at, &format!("    {},\n", entry));
+
+    // The modules are declared above the list, or start a new block if there are none yet
+    let declaration = format!("mod {};\n", module_name(day));
+    let mut declare_at = None;
+    let mut offset = 0;
+    for line in src[..start].split_inclusive('\n') {
+        if line.starts_with("mod day") {
+            declare_at = Some(offset + line.len());
+            if line > declaration.as_str() {
+                declare_at = Some(offset);
+                break;
+            }
+        }
+        offset += line.len();
+    }
+    match declare_at {
+        Some(i) => src.insert_str(i, &declaration),
+        None => src.insert_str(start, &format!("{}\n", declaration)),
+    }
+    Ok(src)
+}
+
+/// The string literal a day's module includes a file with, where a relative `path` is from
+/// the root of the repository and the module is two directories down in `src/y<year>/`.
+fn include_path(path: &Path) -> String {
+    let path = match path.is_absolute() {
+        true => path.to_path_buf(),
+        false => Path::new("..").join("..").join(path),
+    };
+    // Debug formatting quotes and escapes it like a Rust string literal
+    format!("{:?}", path.to_string_lossy())
+}
+
+/// Declare a year module in `src/lib.rs`, keeping the modules in alphabetical order.
+fn declare_year(src: &str, year: u16) -> Result<String, String> {
+    let module = format!("y{}", year);
+    let mut lines: Vec<&str> = src.lines().collect();
+
+    let modules: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let line = line.strip_prefix("pub ").unwrap_or(line);
+            Some((i, line.strip_prefix("mod ")?.strip_suffix(';')?))
+        })
+        .collect();
+    if modules.iter().any(|&(_, name)| name == module) {
+        return Err(format!("Module '{}' is already declared", module));
+    }
+
+    let &(last, _) = modules.last().ok_or("Should declare at least one module")?;
+    let insert_at = modules
+        .iter()
+        .find(|&&(_, name)| name > module.as_str())
+        .map_or(last + 1, |&(i, _)| i);
+
+    let declaration = format!("pub mod {};", module);
+    lines.insert(insert_at, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add a year module to the list in `src/registry.rs`, keeping the years in order.
+fn add_year(src: &str, year: u16) -> Result<String, String> {
+    let start = src
+        .find(YEARS_START)
+        .ok_or("Should contain the list of years")?
+        + YEARS_START.len();
+    let end = start
+        + src[start..]
+            .find("];")
+            .ok_or("The list of years should end with '];'")?;
+
+    let mut years: Vec<String> = src[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|year| !year.is_empty())
+        .map(str::to_string)
+        .collect();
+    let entry = format!("crate::y{}::REGISTERED", year);
+    if years.contains(&entry) {
+        return Err(format!("{} is already in the list of years", year));
+    }
+    years.push(entry);
+    years.sort();
+
+    Ok(format!(
+        "{}{}{}",
+        &src[..start],
+        years.join(", "),
+        &src[end..]
+    ))
+}
+
+/// Write a new file, failing rather than replacing one that already exists.
+fn create(root: &Path, path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let full = root.join(path);
+    let err = |e: io::Error| ScaffoldError {
+        path: Some(path.to_path_buf()),
+        reason: e.to_string(),
+    };
+    if let Some(dir) = full.parent() {
+        fs::create_dir_all(dir).map_err(err)?;
+    }
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&full)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(err)
+}
+
+fn read(root: &Path, path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(root.join(path)).map_err(|e| ScaffoldError {
+        path: Some(path.to_path_buf()),
+        reason: e.to_string(),
+    })
+}
+
+/// Generate the module, input and example for a new day, and register it.
+///
+/// `root` is the root of the repository. Nothing is written if the day already has a
+/// module, and an input or example that's already there is left alone.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Scaffolded, ScaffoldError> {
+    if day == 0 || day > LAST_DAY {
+        return Err(ScaffoldError {
+            path: None,
+            reason: format!("Day should be between 1 and {}, found {}", LAST_DAY, day),
+        });
+    }
+
+    let lib_path = Path::new("src").join("lib.rs");
+    if !root.join(&lib_path).is_file() {
+        return Err(ScaffoldError {
+            path: Some(root.join(&lib_path)),
+            reason: "Should be run from the root of the repository".to_string(),
+        });
+    }
+
+    let module = module_path(year, day);
+    if root.join(&module).exists() {
+        return Err(ScaffoldError {
+            path: Some(module),
+            reason: format!("Day {} of {} already exists", day, year),
+        });
+    }
+
+    // Work out every change to existing files before writing anything
+    let year_module = year_path(year);
+    let new_year = !root.join(&year_module).exists();
+    let mut updates = Vec::new();
+    let year_src = match new_year {
+        true => YEAR_TEMPLATE.to_string(),
+        false => read(root, &year_module)?,
+    };
+    let year_src = register_day(&year_src, day).map_err(|reason| ScaffoldError {
+        path: Some(year_module.clone()),
+        reason,
+    })?;
+    if new_year {
+        let registry_path = Path::new("src").join("registry.rs");
+        for (path, edit) in [
+            (
+                lib_path,
+                declare_year as fn(&str, u16) -> Result<String, String>,
+            ),
+            (registry_path, add_year),
+        ] {
+            let src = edit(&read(root, &path)?, year).map_err(|reason| ScaffoldError {
+                path: Some(path.clone()),
+                reason,
+            })?;
+            updates.push((path, src));
+        }
+    }
+
+    let mut scaffolded = Scaffolded::default();
+    let example_dir = examples::day_dir(year, day);
+    let name = type_name(day);
+    let day_src = DAY_TEMPLATE
+        .replace("{name}", &name)
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{example}", &include_path(&example_dir.join("example.txt")));
+    create(root, &module, &day_src)?;
+    scaffolded.created.push(module);
+
+    match new_year {
+        true => {
+            create(root, &year_module, &year_src)?;
+            scaffolded.created.push(year_module);
+        }
+        false => updates.push((year_module, year_src)),
+    }
+    for (path, src) in updates {
+        fs::write(root.join(&path), src).map_err(|e| ScaffoldError {
+            path: Some(path.clone()),
+            reason: e.to_string(),
+        })?;
+        scaffolded.updated.push(path);
+    }
+
+    // The input and example are left alone if they're already there, e.g. once downloaded
+    for (path, contents) in [
+        (input::default_path(year, day), ""),
+        (example_dir.join("example.txt"), ""),
+        (example_dir.join(examples::EXPECTED_FILE), EXPECTED_TEMPLATE),
+    ] {
+        if !root.join(&path).exists() {
+            create(root, &path, contents)?;
+            scaffolded.created.push(path);
+        }
+    }
+
+    Ok(scaffolded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        let src = "use x;\n\nmod day01;\nmod day03;\n\nregister_days! {\n    day01::Day01,\n    day03::Day03,\n}\n";
+
+        let src = register_day(src, 2).unwrap();
+        assert!(src.contains("    day01::Day01,\n    day02::Day02,\n    day03::Day03,\n}"));
+        assert!(src.contains("mod day01;\nmod day02;\nmod day03;\n\n"));
+
+        let src = register_day(&src, 22).unwrap();
+        assert!(src.ends_with("    day03::Day03,\n    day22::Day22,\n}\n"));
+        assert!(src.contains("mod day03;\nmod day22;\n\n"));
+
+        assert!(register_day(&src, 3).is_err());
+        assert_eq!(
+            register_day(YEAR_TEMPLATE, 1).unwrap(),
+            "use crate::registry::register_days;\n\nmod day01;\n\nregister_days! {\n    day01::Day01,\n}\n"
+        );
+    }
+
+    #[test]
+    fn includes_examples_from_the_module() {
+        assert_eq!(
+            include_path(Path::new("examples/2023/day07/example.txt")),
+            "\"../../examples/2023/day07/example.txt\""
+        );
+        let absolute = std::env::temp_dir().join("example.txt");
+        assert_eq!(
+            include_path(&absolute),
+            format!("{:?}", absolute.to_string_lossy())
+        );
+    }
+
+    #[test]
+    fn declares_new_years() {
+        let lib = "mod answer;\npub mod toml;\npub mod y2023;\n\npub use answer::Answer;\n";
+        assert_eq!(
+            declare_year(lib, 2024).unwrap(),
+            "mod answer;\npub mod toml;\npub mod y2023;\npub mod y2024;\n\npub use answer::Answer;\n"
+        );
+        assert!(declare_year(lib, 2022)
+            .unwrap()
+            .contains("pub mod toml;\npub mod y2022;\npub mod y2023;"));
+        assert!(declare_year(lib, 2023).is_err());
+
+        let registry = "const YEARS: &[&[Entry]] = &[crate::y2023::REGISTERED];\n";
+        assert_eq!(
+            add_year(registry, 2015).unwrap(),
+            "const YEARS: &[&[Entry]] = &[crate::y2015::REGISTERED, crate::y2023::REGISTERED];\n"
+        );
+        assert!(add_year(registry, 2023).is_err());
+    }
+
+    #[test]
+    fn refuses_to_overwrite_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod toml;\n").unwrap();
+        fs::write(
+            root.join("src/registry.rs"),
+            "const YEARS: &[&[Entry]] = &[];\n",
+        )
+        .unwrap();
+
+        let scaffolded = new_day(&root, 2015, 7).unwrap();
+        assert!(scaffolded.created.contains(&module_path(2015, 7)));
+        assert!(scaffolded.created.contains(&year_path(2015)));
+        let day = fs::read_to_string(root.join(module_path(2015, 7))).unwrap();
+        assert!(day.contains("pub struct Day07 {}"));
+        assert!(day.contains("const DAY: u8 = 7;"));
+        let example = examples::day_dir(2015, 7).join("example.txt");
+        assert!(day.contains(&format!("include_str!({})", include_path(&example))));
+        assert!(day.contains("Day07::parse(EXAMPLE)"));
+
+        // A second day only needs registering
+        let scaffolded = new_day(&root, 2015, 1).unwrap();
+        assert_eq!(scaffolded.updated, vec![year_path(2015)]);
+
+        let before = fs::read_to_string(root.join(year_path(2015))).unwrap();
+        assert!(new_day(&root, 2015, 7).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join(year_path(2015))).unwrap(),
+            before
+        );
+        assert!(new_day(&root, 2015, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,337 @@
+//! Searching graphs that are given as starting states and a function listing each state's
+//! neighbours, so the graph never has to be built up front.
+//!
+//! Every search stops at the first state that `is_goal` accepts, or explores every reachable
+//! state if it never does, and remembers how it reached each state so that paths can be
+//! rebuilt afterwards:
+//!
+//! ```ignore
+//! let search = search::dijkstra(starts, |state| moves(state), |state| state.position == end);
+//! let heat_loss = search.goal_cost();
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The states a search reached, with the cheapest known cost of each and where it came from.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    /// The first state found that the search was looking for, if any.
+    pub goal: Option<S>,
+    visits: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    /// The cheapest cost found to a state.
+    ///
+    /// Costs are only sure to be the cheapest for states settled before the search stopped,
+    /// which includes the goal and every state on the path to it.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.visits.get(state).map(|&(cost, _)| cost)
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// The states from a start to `state`, including both ends.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let mut path = vec![state.clone()];
+        let mut current = state;
+        while let Some(previous) = &self.visits.get(current)?.1 {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Every state reached with its cost, in no particular order.
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> + '_ {
+        self.visits.iter().map(|(state, &(cost, _))| (state, cost))
+    }
+}
+
+/// Breadth first search, where each step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visits = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if visits.insert(start.clone(), (0, None)).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Search {
+                goal: Some(state),
+                visits,
+            };
+        }
+        let distance = visits[&state].0 + 1;
+        for next in neighbours(&state) {
+            if !visits.contains_key(&next) {
+                visits.insert(next.clone(), (distance, Some(state.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Search { goal: None, visits }
+}
+
+/// Dijkstra's algorithm, where `neighbours` gives each neighbour with the cost of moving to
+/// it.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which tries the states that `heuristic` says are closest to a goal first.
+///
+/// The heuristic must never overestimate the cost to reach a goal, or the goal found might
+/// not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visits = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        visits.insert(start.clone(), (C::default(), None));
+        heap.push(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            state: start,
+        });
+    }
+
+    while let Some(Queued { cost, state, .. }) = heap.pop() {
+        // A cheaper way here was queued after this one
+        if visits[&state].0 < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            return Search {
+                goal: Some(state),
+                visits,
+            };
+        }
+        for (next, step) in neighbours(&state) {
+            let cost = cost + step;
+            if visits.get(&next).is_none_or(|&(known, _)| cost < known) {
+                visits.insert(next.clone(), (cost, Some(state.clone())));
+                heap.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    Search { goal: None, visits }
+}
+
+/// Dijkstra's algorithm with a queue of buckets, one per cost, which is faster than a heap
+/// when the costs of moves are small integers.
+pub fn bucket<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visits = HashMap::new();
+    let mut buckets: Vec<Vec<S>> = vec![Vec::new()];
+    for start in starts {
+        visits.insert(start.clone(), (0, None));
+        buckets[0].push(start);
+    }
+
+    let mut cost = 0;
+    while cost < buckets.len() {
+        // Moves that cost nothing add to the bucket being emptied
+        while let Some(state) = buckets[cost].pop() {
+            if visits[&state].0 < cost {
+                continue;
+            }
+            if is_goal(&state) {
+                return Search {
+                    goal: Some(state),
+                    visits,
+                };
+            }
+            for (next, step) in neighbours(&state) {
+                let next_cost = cost + step;
+                if visits
+                    .get(&next)
+                    .is_none_or(|&(known, _)| next_cost < known)
+                {
+                    visits.insert(next.clone(), (next_cost, Some(state.clone())));
+                    if next_cost >= buckets.len() {
+                        buckets.resize_with(next_cost + 1, Vec::new);
+                    }
+                    buckets[next_cost].push(next);
+                }
+            }
+        }
+        cost += 1;
+    }
+
+    Search { goal: None, visits }
+}
+
+/// A state waiting in the heap, popped cheapest `priority` first.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small road map, where the direct road from A to D is longer than going round.
+    fn roads(town: &char) -> Vec<(char, u32)> {
+        match town {
+            'A' => vec![('B', 1), ('D', 10)],
+            'B' => vec![('C', 2)],
+            'C' => vec![('D', 3), ('A', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first_counts_steps() {
+        let search = bfs(
+            ['A'],
+            |&town| roads(&town).into_iter().map(|(next, _)| next),
+            |_| false,
+        );
+        assert_eq!(search.goal, None);
+        assert_eq!(search.cost(&'D'), Some(1));
+        assert_eq!(search.cost(&'C'), Some(2));
+        assert_eq!(search.costs().count(), 4);
+
+        let search = bfs(
+            ['A'],
+            |&town| roads(&town).into_iter().map(|(next, _)| next),
+            |&town| town == 'C',
+        );
+        assert_eq!(search.goal_path(), Some(vec!['A', 'B', 'C']));
+    }
+
+    #[test]
+    fn weighted_searches_find_the_cheapest_path() {
+        let search = dijkstra(['A'], roads, |&town| town == 'D');
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.goal_path(), Some(vec!['A', 'B', 'C', 'D']));
+
+        let search = astar(
+            ['A'],
+            roads,
+            |&town| ('D' as u32).abs_diff(town as u32),
+            |&town| town == 'D',
+        );
+        assert_eq!(search.goal_cost(), Some(6));
+
+        let search = bucket(
+            ['A'],
+            |&town| {
+                roads(&town)
+                    .into_iter()
+                    .map(|(next, cost)| (next, cost as usize))
+            },
+            |&town| town == 'D',
+        );
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.goal_path(), Some(vec!['A', 'B', 'C', 'D']));
+    }
+
+    #[test]
+    fn unreachable_goals_explore_everything() {
+        let search = dijkstra(['B'], roads, |&town| town == 'E');
+        assert_eq!(search.goal, None);
+        assert_eq!(search.goal_cost(), None);
+        assert_eq!(search.cost(&'D'), Some(5));
+        assert_eq!(search.path(&'D'), Some(vec!['B', 'C', 'D']));
+        assert_eq!(search.path(&'E'), None);
+    }
+
+    #[test]
+    fn bucket_queue_handles_free_moves() {
+        // Moving along a row is free, moving down a row costs 1
+        let search = bucket(
+            [(0, 0)],
+            |&(x, y): &(u8, u8)| {
+                let mut moves = vec![];
+                if x < 3 {
+                    moves.push(((x + 1, y), 0));
+                }
+                if y < 3 {
+                    moves.push(((x, y + 1), 1));
+                }
+                moves
+            },
+            |_| false,
+        );
+        assert_eq!(search.cost(&(3, 0)), Some(0));
+        assert_eq!(search.cost(&(3, 3)), Some(3));
+    }
+}
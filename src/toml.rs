@@ -0,0 +1,194 @@
+use std::fmt::{Display, Error, Formatter};
+
+/// A value in the subset of TOML used by the answer and example files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i128),
+    Str(String),
+    Array(Vec<String>),
+}
+
+/// A `[name]` header and the `key = value` pairs that follow it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub name: String,
+    /// Line of the header, counting from 1.
+    pub line: usize,
+    /// Each key and value, along with the line it was on.
+    pub entries: Vec<(String, Value, usize)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TomlError {
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Display for TomlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for TomlError {}
+
+/// Parse tables of integers, strings and arrays of strings.
+///
+/// Comments, blank lines, `_` digit separators and the basic string escapes are supported,
+/// but nested tables, inline tables and other value types are not.
+pub fn parse(src: &str) -> Result<Vec<Table>, TomlError> {
+    let mut tables: Vec<Table> = Vec::new();
+
+    for (i, line) in src.lines().enumerate() {
+        let err = |reason: String| TomlError {
+            line: i + 1,
+            reason,
+        };
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| err(format!("Invalid table header '{}'", line)))?;
+            if tables.iter().any(|table| table.name == name) {
+                return Err(err(format!("Table '{}' is defined more than once", name)));
+            }
+            tables.push(Table {
+                name: name.to_string(),
+                line: i + 1,
+                entries: Vec::new(),
+            });
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| err(format!("Expected 'key = value', found '{}'", line)))?;
+        let key = key.trim();
+        let table = tables
+            .last_mut()
+            .ok_or_else(|| err(format!("Key '{}' given before any table header", key)))?;
+        if table.entries.iter().any(|(k, _, _)| k == key) {
+            return Err(err(format!(
+                "Key '{}' is given more than once in '{}'",
+                key, table.name
+            )));
+        }
+        let value = parse_value(value.trim()).map_err(err)?;
+        table.entries.push((key.to_string(), value, i + 1));
+    }
+
+    Ok(tables)
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(items) = value.strip_prefix('[') {
+        let items = items
+            .strip_suffix(']')
+            .ok_or_else(|| format!("Unterminated array '{}'", value))?;
+        let mut strings = Vec::new();
+        let mut rest = items.trim();
+        while !rest.is_empty() {
+            let (s, remaining) = parse_string(rest)?;
+            strings.push(s);
+            rest = remaining.trim_start();
+            rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+        }
+        return Ok(Value::Array(strings));
+    }
+
+    if value.starts_with('"') {
+        let (s, rest) = parse_string(value)?;
+        if !rest.trim().is_empty() {
+            return Err(format!("Unexpected '{}' after string", rest.trim()));
+        }
+        return Ok(Value::Str(s));
+    }
+
+    value
+        .replace('_', "")
+        .parse::<i128>()
+        .map(Value::Int)
+        .map_err(|_| format!("Expected an integer, string or array, found '{}'", value))
+}
+
+/// Parse a double quoted string from the start of `s`, returning it and the remainder.
+fn parse_string(s: &str) -> Result<(String, &str), String> {
+    let body = s
+        .strip_prefix('"')
+        .ok_or_else(|| format!("Expected a string, found '{}'", s))?;
+
+    let mut out = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &body[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, '"')) => out.push('"'),
+                Some((_, '\\')) => out.push('\\'),
+                Some((_, c)) => return Err(format!("Unknown escape '\\{}'", c)),
+                None => break,
+            },
+            c => out.push(c),
+        }
+    }
+
+    Err(format!("Unterminated string {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tables() {
+        let tables = parse(
+            r##"
+            # Comments and blank lines are ignored
+            [day01]
+            part1 = 54_239
+            part2 = "ABC \"quoted\""
+
+            [day10]
+            part1 = ["#..", ".#."]
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].name, "day01");
+        assert_eq!(tables[0].line, 3);
+        assert_eq!(
+            tables[0].entries,
+            vec![
+                ("part1".to_string(), Value::Int(54239), 4),
+                (
+                    "part2".to_string(),
+                    Value::Str("ABC \"quoted\"".to_string()),
+                    5
+                ),
+            ]
+        );
+        assert_eq!(
+            tables[1].entries[0].1,
+            Value::Array(vec!["#..".to_string(), ".#.".to_string()])
+        );
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        assert_eq!(parse("part1 = 1").unwrap_err().line, 1);
+        assert_eq!(parse("[a]\nb = 1\nb = 2").unwrap_err().line, 3);
+        assert_eq!(parse("[a]\n[a]").unwrap_err().line, 2);
+        assert_eq!(parse("[a]\n\nb = \"open").unwrap_err().line, 3);
+        assert_eq!(parse("[a]\nb = 1x").unwrap_err().line, 2);
+    }
+}
@@ -0,0 +1,162 @@
+//! Polling for changes to the files a day depends on, and showing how its answers changed.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// How often to check the files for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time and size of every watched file, or `None` if it's missing.
+///
+/// Directories are watched by the files inside them, so that new files are noticed too.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<(SystemTime, u64)>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut snapshot = Self::default();
+        for path in paths {
+            snapshot.add(path);
+        }
+        snapshot
+    }
+
+    fn add(&mut self, path: &Path) {
+        match fs::metadata(path) {
+            Ok(meta) if meta.is_dir() => {
+                let entries = fs::read_dir(path)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Result::ok);
+                for entry in entries {
+                    self.add(&entry.path());
+                }
+            }
+            Ok(meta) => {
+                // Some filesystems don't record modification times, so fall back on the size
+                let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                self.0
+                    .insert(path.to_path_buf(), Some((modified, meta.len())));
+            }
+            // A file that can't be read is as good as missing, and reported when it's used
+            Err(_) => {
+                self.0.insert(path.to_path_buf(), None);
+            }
+        }
+    }
+
+    /// The files that have been created, changed or removed since this snapshot.
+    pub fn changed(&self, newer: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = newer
+            .0
+            .iter()
+            .filter(|&(path, state)| self.0.get(path) != Some(state))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.0
+                .keys()
+                .filter(|path| !newer.0.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        changed
+    }
+}
+
+/// A line of the difference between two texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// The lines removed from and added to `before` to make `after`, keeping as many the
+/// same as possible.
+pub fn diff<'a>(before: &'a str, after: &'a str) -> Vec<Line<'a>> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+
+    // Longest common subsequence of every pair of suffixes
+    let mut common = vec![vec![0; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            common[i][j] = match before[i] == after[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < before.len() || j < after.len() {
+        if i < before.len() && j < after.len() && before[i] == after[j] {
+            lines.push(Line::Same(before[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < before.len() && (j == after.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(before[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(after[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notices_changed_new_and_removed_files() {
+        let dir = std::env::temp_dir().join(format!("advent_of_code-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("examples")).unwrap();
+        let input = dir.join("input.txt");
+        let example = dir.join("examples").join("example.txt");
+        fs::write(&input, "1 2 3\n").unwrap();
+        fs::write(&example, "1\n").unwrap();
+
+        let paths = [input.clone(), dir.join("examples")];
+        let before = Snapshot::take(&paths);
+        assert_eq!(
+            before.changed(&Snapshot::take(&paths)),
+            Vec::<PathBuf>::new()
+        );
+
+        fs::write(&input, "1 2 3 4\n").unwrap();
+        fs::remove_file(&example).unwrap();
+        let added = dir.join("examples").join("example2.txt");
+        fs::write(&added, "2\n").unwrap();
+        let after = Snapshot::take(&paths);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut expected = vec![input, example, added];
+        expected.sort();
+        assert_eq!(before.changed(&after), expected);
+    }
+
+    #[test]
+    fn diffs_lines() {
+        use Line::*;
+        assert_eq!(diff("7939", "7940"), [Removed("7939"), Added("7940")]);
+        assert_eq!(
+            diff("#..\n.#.\n..#", "#..\n###\n..#\n..."),
+            [
+                Same("#.."),
+                Removed(".#."),
+                Added("###"),
+                Same("..#"),
+                Added("...")
+            ]
+        );
+        assert_eq!(diff("", "1"), [Added("1")]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{Answer, Day, Error, ParseError};
+
+pub struct Day01 {}
+
+impl Day01 {
+    fn no_digit(line: &str) -> Error {
+        Error::solve(format!("Line '{}' doesn't contain a digit", line))
+    }
+
+    fn get_calibration(line: &str) -> Result<u32, Error> {
+        let first = line
+            .bytes()
+            .find(|&b| b.is_ascii_digit())
+            .ok_or_else(|| Self::no_digit(line))?;
+
+        let last = line
+            .bytes()
+            .rfind(|&b| b.is_ascii_digit())
+            .ok_or_else(|| Self::no_digit(line))?;
+
+        Ok((10 * (first - 48) + (last - 48)) as u32)
+    }
+
+    fn get_calibration_p2(line: &str) -> Result<u32, Error> {
+        const DIGITS: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+
+        let mut first = None;
+        let mut last = 0;
+
+        // Simplify digit update
+        let mut digit = |b| {
+            first = first.or(Some(b));
+            last = b;
+        };
+
+        let chars = line.as_bytes();
+        for (i, b) in chars.iter().enumerate() {
+            if b.is_ascii_digit() {
+                digit(b - b'0');
+            } else {
+                for (j, d) in DIGITS.iter().enumerate() {
+                    if chars[i..].starts_with(d.as_bytes()) {
+                        digit(j as u8 + 1);
+                    }
+                }
+            }
+        }
+
+        let first = first.ok_or_else(|| Self::no_digit(line))?;
+        Ok((10 * first + last) as u32)
+    }
+}
+
+impl Day for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn problem1(lines: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output = lines
+            .iter()
+            .map(|line| Self::get_calibration(line))
+            .sum::<Result<u32, _>>()?;
+        Ok(output.into())
+    }
+
+    fn problem2(lines: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output = lines
+            .iter()
+            .map(|line| Self::get_calibration_p2(line))
+            .sum::<Result<u32, _>>()?;
+        Ok(output.into())
+    }
+}
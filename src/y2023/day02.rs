@@ -0,0 +1,77 @@
+use crate::{Answer, Day, Error, ParseError};
+use std::collections::HashMap;
+
+pub struct Day02 {}
+
+impl Day02 {
+    const COLORS: [&'static str; 3] = ["red", "green", "blue"];
+
+    fn process_games(input: &str) -> Result<Vec<HashMap<&str, i32>>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (_, rounds) = line.split_once(':').ok_or_else(|| {
+                    ParseError::at(input, line, "Game should be 'Game <id>: <rounds>'")
+                })?;
+
+                let mut acc = HashMap::new();
+                for draw in rounds.split([';', ',']) {
+                    let draw = draw.trim();
+                    let (count, color) = draw.split_once(' ').ok_or_else(|| {
+                        ParseError::at(input, draw, "Draw should be '<count> <color>'")
+                    })?;
+                    let count: i32 = ParseError::parse(input, count, "a count")?;
+                    if !Self::COLORS.contains(&color) {
+                        return Err(ParseError::at(
+                            input,
+                            color,
+                            format!("Colour should be red, green or blue, found '{}'", color),
+                        ));
+                    }
+                    let cc = acc.entry(color).or_insert(0);
+                    *cc = i32::max(*cc, count);
+                }
+                Ok(acc)
+            })
+            .collect()
+    }
+}
+
+impl Day for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<HashMap<&'a str, i32>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::process_games(input)
+    }
+
+    fn problem1(games: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut maxes = HashMap::with_capacity(3);
+        maxes.insert("red", 12);
+        maxes.insert("green", 13);
+        maxes.insert("blue", 14);
+
+        let total: usize = games
+            .iter()
+            .enumerate()
+            .filter(|(_, counts)| {
+                // Colours are checked while parsing, so are always in the map
+                counts.iter().all(|(k, v)| v <= &maxes[k])
+            })
+            .map(|(i, _)| i + 1)
+            .sum();
+
+        Ok(total.into())
+    }
+
+    fn problem2(games: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let total: i32 = games
+            .iter()
+            .map(|counts| counts.values().product::<i32>())
+            .sum();
+
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+
+use crate::{grid::Grid, point::Point, Answer, Day, Error, ParseError};
+
+pub struct Day03 {}
+
+impl Day03 {
+    /// The position of the first digit of the number with a digit at `digit`.
+    fn number_start(schematic: &Grid<u8>, mut digit: Point<usize>) -> Point<usize> {
+        let row = schematic.row(digit.y);
+        while digit.x > 0 && row[digit.x - 1].is_ascii_digit() {
+            digit.x -= 1;
+        }
+        digit
+    }
+
+    fn number_at(schematic: &Grid<u8>, start: Point<usize>) -> Result<u32, Error> {
+        let row = &schematic.row(start.y)[start.x..];
+        let end = row
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .unwrap_or(row.len());
+        // The schematic is all ASCII, so the digits are valid UTF-8
+        let number = std::str::from_utf8(&row[..end]).expect("Digits should be ASCII");
+        number
+            .parse()
+            .map_err(|err| Error::solve(format!("Part number {}: {}", number, err)))
+    }
+}
+
+impl Day for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Grid::parse(input, |c| match c.is_ascii() {
+            true => Ok(c as u8),
+            false => Err("Schematic should only contain ASCII characters".to_string()),
+        })
+    }
+
+    fn problem1(schematic: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        const SYMBOLS: [char; 30] = [
+            '-', '!', '$', '%', '^', '&', '*', '(', ')', '_', '+', '|', '~', '=', '`', '{', '}',
+            '[', ']', ':', '"', ';', '\'', '<', '>', '?', ',', '/', '#', '@',
+        ];
+
+        let mut total = 0;
+        for (y, row) in schematic.rows().enumerate() {
+            let mut num = 0;
+            let mut valid = false;
+
+            for (x, &c) in row.iter().enumerate() {
+                if c.is_ascii_digit() {
+                    num *= 10;
+                    num += (c - b'0') as u32;
+
+                    if valid {
+                        continue;
+                    }
+
+                    valid = schematic
+                        .surrounding(Point::new(x, y))
+                        .any(|position| SYMBOLS.contains(&(schematic[position] as char)));
+                } else {
+                    if valid {
+                        total += num;
+                    }
+                    num = 0;
+                    valid = false;
+                }
+            }
+            if valid {
+                total += num;
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn problem2(schematic: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut total = 0;
+        for (position, &c) in schematic.iter() {
+            if c != b'*' {
+                continue;
+            }
+
+            // Find the start of each number around the star
+            let numbers: HashSet<_> = schematic
+                .surrounding(position)
+                .filter(|&around| schematic[around].is_ascii_digit())
+                .map(|digit| Self::number_start(schematic, digit))
+                .collect();
+
+            // Only run when there are two numbers
+            if numbers.len() != 2 {
+                continue;
+            }
+
+            total += numbers
+                .into_iter()
+                .map(|start| Self::number_at(schematic, start))
+                .product::<Result<u32, _>>()?;
+        }
+
+        Ok(total.into())
+    }
+}
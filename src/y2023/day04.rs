@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use crate::{Answer, Day, Error, ParseError};
+
+pub struct Day04 {}
+
+impl Day04 {
+    fn scores(input: &str) -> Result<Vec<u32>, ParseError> {
+        let numbers = |list: &str| -> Result<Vec<u32>, ParseError> {
+            list.split_whitespace()
+                .map(|c| ParseError::parse(input, c, "a number"))
+                .collect()
+        };
+
+        input
+            .lines()
+            .map(|line| {
+                let start = line.find(':').ok_or_else(|| {
+                    ParseError::at(input, line, "Card should be 'Card <num>: ...'")
+                })? + 1;
+                let (winning, got) = line[start..].split_once('|').ok_or_else(|| {
+                    ParseError::at(input, line, "Every card should have a vertical bar")
+                })?;
+
+                let got: HashSet<u32> = numbers(got)?.into_iter().collect();
+                let won = numbers(winning)?.into_iter().fold(0, |won, num| {
+                    if got.contains(&num) {
+                        won + 1
+                    } else {
+                        won
+                    }
+                });
+                Ok(won)
+            })
+            .collect()
+    }
+}
+
+impl Day for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::scores(input)
+    }
+
+    fn problem1(scores: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let total: u32 = scores
+            .iter()
+            .filter(|&&c| c != 0)
+            .map(|score| u32::pow(2, score - 1))
+            .sum();
+        Ok(total.into())
+    }
+
+    fn problem2(scores: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut mults = vec![1; scores.len()];
+        let mut total = 0;
+        for (i, &score) in scores.iter().enumerate() {
+            if i + score as usize >= mults.len() && score > 0 {
+                return Err(Error::solve(format!(
+                    "Card {} wins copies of cards past the end of the table",
+                    i + 1
+                )));
+            }
+            for j in i..i + score as usize {
+                mults[j + 1] += mults[i];
+            }
+            total += mults[i];
+        }
+        Ok(total.into())
+    }
+}
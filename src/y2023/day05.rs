@@ -0,0 +1,110 @@
+use crate::{
+    input,
+    interval::{Interval, IntervalSet},
+    log, Answer, Day, Error, ParseError,
+};
+
+pub struct Day05 {}
+
+impl Day05 {
+    fn get_maps(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+        let maps = input::blocks(input)
+            .enumerate()
+            .map(|(i, section)| {
+                let start = section.find(':').ok_or_else(|| {
+                    ParseError::at(input, section, "Should contain header followed by ':'")
+                })?;
+                let values = section[start + 1..]
+                    .split_whitespace()
+                    .map(|val| ParseError::parse(input, val, "a number"))
+                    .collect::<Result<Vec<u64>, _>>()?;
+
+                // Every section after the seeds is made up of ranges
+                if i > 0 && values.len() % 3 != 0 {
+                    return Err(ParseError::at(
+                        input,
+                        section,
+                        "Map should have three values on every line",
+                    ));
+                }
+                Ok(values)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match maps.is_empty() {
+            true => Err(ParseError::new("Almanac should start with a list of seeds")),
+            false => Ok(maps),
+        }
+    }
+}
+
+impl Day for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::get_maps(input)
+    }
+
+    fn problem1(maps: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let seeds = &maps[0];
+        let closest = seeds
+            .iter()
+            .map(|&seed| {
+                let mut next = seed;
+                for map in &maps[1..] {
+                    next = match map
+                        .chunks(3)
+                        .find(|win| win[1] <= next && next < win[1] + win[2])
+                    {
+                        Some(win) => (win[0] + next) - win[1],
+                        None => next,
+                    };
+                }
+                next
+            })
+            .min()
+            .ok_or_else(|| Error::solve("There should be at least one seed"))?;
+        Ok(closest.into())
+    }
+
+    fn problem2(maps: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        if maps[0].len() % 2 != 0 {
+            return Err(Error::solve("Seeds should be in pairs of start and length"));
+        }
+        let mut seeds: IntervalSet<u64> = maps[0]
+            .chunks(2)
+            .map(|seed| Interval::new(seed[0], seed[0] + seed[1]))
+            .collect();
+
+        for (i, map) in maps[1..].iter().enumerate() {
+            let mut unmapped = seeds.clone();
+            let mut mapped = IntervalSet::new();
+            for win in map.chunks(3) {
+                let source = IntervalSet::from(Interval::new(win[1], win[1] + win[2]));
+                for range in unmapped.intersection(&source).iter() {
+                    let moved =
+                        Interval::new(range.start - win[1] + win[0], range.end - win[1] + win[0]);
+                    log::debug!("Map {}: {} to {}", i + 1, range, moved);
+                    mapped.insert(moved);
+                }
+                unmapped = unmapped.difference(&source);
+            }
+            // Anything outside every source range keeps its number
+            let working = mapped.union(&unmapped);
+            log::info!(
+                "Map {} split {} ranges into {}",
+                i + 1,
+                seeds.iter().count(),
+                working.iter().count()
+            );
+            seeds = working;
+        }
+        let lowest = seeds
+            .min()
+            .ok_or_else(|| Error::solve("There should be at least one seed"))?;
+        Ok(lowest.into())
+    }
+}
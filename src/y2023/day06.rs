@@ -0,0 +1,70 @@
+use crate::{Answer, Day, Error, ParseError};
+
+pub struct Day06 {}
+
+impl Day06 {
+    /// How many ways there are to hold the button that beat the best distance.
+    fn ways_to_win((max_time, best): (u64, u64)) -> usize {
+        (0..max_time)
+            .map(|time| time * (max_time - time))
+            .filter(|&distance| distance > best)
+            .count()
+    }
+}
+
+impl Day for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    // The time and record distance of each race, and of the one race they really are
+    type Parsed<'a> = (Vec<(u64, u64)>, (u64, u64));
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut lines = input
+            .lines()
+            .map(|line| -> Result<(Vec<u64>, u64), ParseError> {
+                let (_, scores) = line
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::at(input, line, "Lines should contain ':'"))?;
+                let numbers = scores
+                    .split_whitespace()
+                    .map(|score| ParseError::parse(input, score, "a number"))
+                    .collect::<Result<Vec<u64>, _>>()?;
+
+                if numbers.is_empty() {
+                    return Err(ParseError::at(input, line, "Should be at least one race"));
+                }
+
+                // The numbers are really one long number, with bad kerning
+                let joined = scores.split_whitespace().collect::<String>();
+                let joined = joined.parse().map_err(|_| {
+                    ParseError::at(input, scores.trim(), "Joined number should fit in a u64")
+                })?;
+                Ok((numbers, joined))
+            });
+
+        let (times, time) = lines
+            .next()
+            .ok_or_else(|| ParseError::new("First line should be times"))??;
+        let (best, distance) = lines
+            .next()
+            .ok_or_else(|| ParseError::new("Second line should be distances"))??;
+        if times.len() != best.len() {
+            return Err(ParseError::new(
+                "Every race should have a time and distance",
+            ));
+        }
+        Ok((times.into_iter().zip(best).collect(), (time, distance)))
+    }
+
+    fn problem1((races, _): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // Calculate number of possible ways to exceed best
+        let output: usize = races.iter().map(|&race| Self::ways_to_win(race)).product();
+
+        Ok(output.into())
+    }
+
+    fn problem2((_, race): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::ways_to_win(*race).into())
+    }
+}
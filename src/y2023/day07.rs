@@ -1,8 +1,6 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use advent_of_code::Day;
-
-const SRC: &str = include_str!("../../input/day07.txt");
+use crate::{Answer, Day, Error, ParseError};
 
 #[derive(Debug, PartialEq, PartialOrd)]
 enum Rank {
@@ -50,43 +48,41 @@ impl Rank {
 pub struct Day07 {}
 
 impl Day07 {
+    // Hands are checked while parsing, so always map to five cards
     fn map_hand(hand: &str, is_p2: bool) -> [u8; 5] {
-        hand.chars()
-            .map(|c| match c {
-                'A' => 14,
-                'K' => 13,
-                'Q' => 12,
-                'J' => {
-                    if !is_p2 {
-                        11
-                    } else {
-                        1
-                    }
-                }
-                'T' => 10,
-                '2'..='9' => (c as u8) - b'0',
-                _ => panic!("Hand should only consist of characters AKQJT9-2"),
-            })
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect("Hand should contain 5 cards")
+        let mut cards = [0; 5];
+        for (card, c) in cards.iter_mut().zip(hand.bytes()) {
+            *card = match c {
+                b'A' => 14,
+                b'K' => 13,
+                b'Q' => 12,
+                b'J' if is_p2 => 1,
+                b'J' => 11,
+                b'T' => 10,
+                _ => c - b'0',
+            };
+        }
+        cards
     }
 
-    fn solve_problem(is_p2: bool) -> u32 {
-        let mut hands: Vec<_> = SRC
-            .lines()
-            .map(|line| {
-                line.split_once(' ')
-                    .expect("Each line should be: '<CCCCC> <bet>'")
-            })
-            .map(|(hand, bet)| {
-                let hand = Self::map_hand(hand, is_p2);
-                let bet = bet
-                    .parse::<u32>()
-                    .ok()
-                    .expect("Bet should be parsable as number");
-                (hand, bet)
-            })
+    fn check_hand<'a>(input: &str, hand: &'a str) -> Result<&'a str, ParseError> {
+        if let Some(i) = hand.find(|c| !"AKQJT98765432".contains(c)) {
+            return Err(ParseError::at(
+                input,
+                &hand[i..],
+                "Hand should only consist of characters AKQJT9-2",
+            ));
+        }
+        match hand.len() {
+            5 => Ok(hand),
+            _ => Err(ParseError::at(input, hand, "Hand should contain 5 cards")),
+        }
+    }
+
+    fn solve_problem(hands: &[(&str, u32)], is_p2: bool) -> u32 {
+        let mut hands: Vec<_> = hands
+            .iter()
+            .map(|&(hand, bet)| (Self::map_hand(hand, is_p2), bet))
             .map(|(hand, bet)| (Rank::get(hand, is_p2), hand, bet))
             .collect();
 
@@ -120,13 +116,33 @@ impl Day07 {
 }
 
 impl Day for Day07 {
-    fn problem1() {
-        let output = Self::solve_problem(false);
-        println!("{:?}", output);
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    // Each hand along with its bet
+    type Parsed<'a> = Vec<(&'a str, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (hand, bet) = line.split_once(' ').ok_or_else(|| {
+                    ParseError::at(input, line, "Each line should be: '<CCCCC> <bet>'")
+                })?;
+                let hand = Self::check_hand(input, hand)?;
+                let bet = ParseError::parse(input, bet, "a bet")?;
+                Ok((hand, bet))
+            })
+            .collect()
+    }
+
+    fn problem1(hands: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output = Self::solve_problem(hands, false);
+        Ok(output.into())
     }
 
-    fn problem2() {
-        let output = Self::solve_problem(true);
-        println!("{:?}", output);
+    fn problem2(hands: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output = Self::solve_problem(hands, true);
+        Ok(output.into())
     }
 }
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::{input, log, math, Answer, Day, Error, ParseError};
+
+#[derive(Debug)]
+pub struct MapEntry<'a> {
+    left: &'a str,
+    right: &'a str,
+}
+
+impl<'a> MapEntry<'a> {
+    fn new(left: &'a str, right: &'a str) -> Self {
+        Self {
+            left: left.trim().trim_start_matches('('),
+            right: right.trim().trim_end_matches(')'),
+        }
+    }
+}
+
+/// The steps after which a ghost is on a node ending in Z.
+///
+/// Once a ghost is back on a node at the same point in the directions, it goes round the same
+/// cycle forever, so it reaches Z at each of `before` once and then at each of `cycle` every
+/// `length` steps.
+struct Ghost {
+    before: Vec<usize>,
+    cycle: Vec<usize>,
+    length: usize,
+}
+
+impl Ghost {
+    fn at_end(&self, steps: usize) -> bool {
+        self.before.contains(&steps)
+            || self
+                .cycle
+                .iter()
+                .any(|&hit| steps >= hit && (steps - hit).is_multiple_of(self.length))
+    }
+}
+
+pub struct Day08 {}
+
+impl Day08 {
+    fn parse_input(input: &str) -> Result<(&str, HashMap<&str, MapEntry<'_>>), ParseError> {
+        let mut blocks = input::blocks(input);
+        let directions = blocks
+            .next()
+            .ok_or_else(|| ParseError::new("Should contain directions as first line"))?;
+        if let Some(i) = directions.find(|c| c != 'L' && c != 'R') {
+            return Err(ParseError::at(
+                input,
+                &directions[i..],
+                "Direction should either be left or right",
+            ));
+        }
+
+        let map = blocks
+            .next()
+            .ok_or_else(|| ParseError::new("Should contain map after a blank line"))?
+            .lines()
+            .map(|line| {
+                let (k, v) = line.split_once('=').ok_or_else(|| {
+                    ParseError::at(
+                        input,
+                        line,
+                        "Map should be in form <key> = (<left>, <right>)",
+                    )
+                })?;
+
+                let (l, r) = v.split_once(',').ok_or_else(|| {
+                    ParseError::at(input, v, "Value should be in form (<left>, <right>)")
+                })?;
+                let v = MapEntry::new(l, r);
+
+                Ok((k.trim(), v))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok((directions, map))
+    }
+
+    fn step<'a>(
+        map: &HashMap<&str, MapEntry<'a>>,
+        location: &str,
+        direction: char,
+    ) -> Result<&'a str, Error> {
+        let possibilities = map
+            .get(location)
+            .ok_or_else(|| Error::solve(format!("Location {} should be in map", location)))?;
+
+        Ok(match direction {
+            'L' => possibilities.left,
+            'R' => possibilities.right,
+            _ => unreachable!("Directions are checked while parsing"),
+        })
+    }
+
+    fn ghost(
+        directions: &str,
+        map: &HashMap<&str, MapEntry<'_>>,
+        start: &str,
+    ) -> Result<Ghost, Error> {
+        let mut location = start;
+        let mut seen: HashMap<(&str, usize), usize> = HashMap::new();
+        let mut hits = Vec::new();
+        for (steps, (pos, direction)) in directions.chars().enumerate().cycle().enumerate() {
+            if let Some(cycle_start) = seen.insert((location, pos), steps) {
+                let cycle = hits.split_off(hits.partition_point(|&hit| hit < cycle_start));
+                log::info!(
+                    "Ghost from {} starts a cycle of {} steps after {}, reaching Z at {:?}",
+                    start,
+                    steps - cycle_start,
+                    cycle_start,
+                    cycle
+                );
+                return Ok(Ghost {
+                    before: hits,
+                    cycle,
+                    length: steps - cycle_start,
+                });
+            }
+            if location.ends_with('Z') {
+                hits.push(steps);
+            }
+            location = Self::step(map, location, direction)?;
+        }
+
+        Err(Error::solve("Should be directions to follow"))
+    }
+}
+
+impl Day for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    const QUERIES: &'static [(&'static str, &'static str)] = &[
+        ("node <node>", "Show where a node leads left and right"),
+        (
+            "walk <node> <steps>",
+            "Follow the directions from a node for some steps",
+        ),
+    ];
+
+    type Parsed<'a> = (&'a str, HashMap<&'a str, MapEntry<'a>>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse_input(input)
+    }
+
+    fn problem1((directions, map): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut i = 0;
+        let mut location = "AAA";
+        for direction in directions.chars().cycle() {
+            if location == "ZZZ" {
+                break;
+            }
+            location = Self::step(map, location, direction)?;
+            i += 1;
+        }
+
+        Ok(i.into())
+    }
+
+    fn problem2((directions, map): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let ghosts = map
+            .keys()
+            .filter(|key| key.ends_with("A"))
+            .map(|start| Self::ghost(directions, map, start))
+            .collect::<Result<Vec<_>, _>>()?;
+        if ghosts.is_empty() {
+            return Err(Error::solve("Should be a node ending in A to start from"));
+        }
+
+        // Meeting before one of the ghosts starts its cycle beats meeting once they all have
+        let early = ghosts
+            .iter()
+            .flat_map(|ghost| ghost.before.iter().copied())
+            .filter(|&steps| ghosts.iter().all(|ghost| ghost.at_end(steps)))
+            .min();
+        if let Some(steps) = early {
+            return Ok(steps.into());
+        }
+
+        // Otherwise each choice of where in its cycle each ghost is gives a set of congruences
+        let mut meetings: Vec<(i128, i128, i128)> = vec![(0, 1, 0)];
+        for ghost in &ghosts {
+            meetings = meetings
+                .iter()
+                .flat_map(|&(remainder, modulus, earliest)| {
+                    ghost.cycle.iter().filter_map(move |&hit| {
+                        let (hit, length) = (hit as i128, ghost.length as i128);
+                        let (remainder, modulus) =
+                            math::crt([(remainder, modulus), (hit, length)])?;
+                        Some((remainder, modulus, earliest.max(hit)))
+                    })
+                })
+                .collect();
+        }
+
+        // The first time each set of congruences holds once every ghost is in its cycle
+        meetings
+            .into_iter()
+            .map(|(remainder, modulus, earliest)| {
+                let cycles = (earliest - remainder).max(0) + modulus - 1;
+                remainder + cycles / modulus * modulus
+            })
+            .min()
+            .map(Answer::Int)
+            .ok_or_else(|| Error::solve("Ghosts should all reach a node ending in Z together"))
+    }
+
+    fn explore((directions, map): &mut Self::Parsed<'_>, query: &[&str]) -> Result<String, String> {
+        match query {
+            ["node", node] => match map.get(node) {
+                Some(entry) => Ok(format!("{} = ({}, {})", node, entry.left, entry.right)),
+                None => Err(format!("There's no node {}", node)),
+            },
+            ["walk", node, steps] => {
+                let steps: usize = steps
+                    .parse()
+                    .map_err(|_| format!("Steps should be a number, found '{}'", steps))?;
+                let mut location = *node;
+                for direction in directions.chars().cycle().take(steps) {
+                    location = Self::step(map, location, direction).map_err(|e| e.to_string())?;
+                }
+                Ok(format!(
+                    "{} leads to {} after {} steps",
+                    node, location, steps
+                ))
+            }
+            _ => Err(format!("Unknown query '{}'", query.join(" "))),
+        }
+    }
+}
@@ -1,18 +1,26 @@
-use advent_of_code::Day;
-
-const SRC: &str = include_str!("../../input/day09.txt");
+use crate::{Answer, Day, Error, ParseError};
 
 pub struct Day09 {}
 
 impl Day09 {
-    fn get_parsed_input() -> impl Iterator<Item = Vec<Vec<i32>>> {
-        SRC.lines()
+    fn get_parsed_input(input: &str) -> Result<Vec<Vec<Vec<i32>>>, ParseError> {
+        input
+            .lines()
             .map(|line| {
-                line.split_whitespace()
-                    .map(|n| n.parse::<i32>().expect("Each reading should be an integer"))
-                    .collect::<Vec<i32>>()
+                let readings = line
+                    .split_whitespace()
+                    .map(|n| ParseError::parse(input, n, "an integer reading"))
+                    .collect::<Result<Vec<i32>, _>>()?;
+                match readings.is_empty() {
+                    true => Err(ParseError::at(
+                        input,
+                        line,
+                        "Should be at least one reading",
+                    )),
+                    false => Ok(Self::forward_pass(readings)),
+                }
             })
-            .map(Self::forward_pass)
+            .collect()
     }
 
     fn forward_pass(readings: Vec<i32>) -> Vec<Vec<i32>> {
@@ -43,7 +51,7 @@ impl Day09 {
         for i in (1..n).rev() {
             let upper = &mut pattern[i - 1];
             let val_left = upper[upper.len() - 1];
-            val_right = val_left + val_right;
+            val_right += val_left;
 
             upper.push(val_right);
         }
@@ -72,37 +80,36 @@ impl Day09 {
 }
 
 impl Day for Day09 {
-    fn problem1() {
-        let lines = Self::get_parsed_input();
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    // The differences between readings on each line
+    type Parsed<'a> = Vec<Vec<Vec<i32>>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::get_parsed_input(input)
+    }
 
-        let output: i32 = lines
+    // Every row of a pattern is non-empty, as they're checked while parsing
+    fn problem1(patterns: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output: i32 = patterns
+            .iter()
+            .cloned()
             .map(Self::fill_forward)
-            .map(|row| {
-                row.first()
-                    .expect("Pattern should contain at least one row")
-                    .last()
-                    .expect("Row should contain at least one element")
-                    .to_owned()
-            })
+            .map(|row| row[0][row[0].len() - 1])
             .sum();
 
-        println!("{:?}", output);
+        Ok(output.into())
     }
 
-    fn problem2() {
-        let lines = Self::get_parsed_input();
-
-        let output: i32 = lines
+    fn problem2(patterns: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output: i32 = patterns
+            .iter()
+            .cloned()
             .map(Self::fill_backward)
-            .map(|row| {
-                row.first()
-                    .expect("Pattern should contain at least one row")
-                    .first()
-                    .expect("Row should contain at least one element")
-                    .to_owned()
-            })
+            .map(|row| row[0][0])
             .sum();
 
-        println!("{:?}", output);
+        Ok(output.into())
     }
 }
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::Grid,
+    point::{Direction, Direction::*, Point},
+    Answer, Day, Error, ParseError,
+};
+
+pub struct Day10 {}
+
+impl Day10 {
+    fn get_grid(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c),
+            _ => Err(format!("Tile should be a pipe, '.' or 'S', found '{}'", c)),
+        })
+    }
+
+    fn get_start(grid: &Grid<char>) -> Result<Point<usize>, Error> {
+        grid.find(&'S')
+            .ok_or_else(|| Error::solve("Should be a starting position"))
+    }
+
+    fn get_starting_direction(grid: &Grid<char>, start: Point<usize>) -> Result<Direction, Error> {
+        Direction::ALL
+            .into_iter()
+            .find(|&direction| {
+                grid.step(start, direction.offset())
+                    .is_some_and(|next| Self::valid_neighbour(grid[next], direction))
+            })
+            .ok_or_else(|| Error::solve("Should be a valid neighbour to start"))
+    }
+
+    /// Follow the loop one step, failing if it leads off the grid or into a pipe that doesn't connect.
+    fn step(
+        grid: &Grid<char>,
+        position: Point<usize>,
+        direction: Direction,
+    ) -> Result<(Point<usize>, Direction), Error> {
+        let next = grid
+            .step(position, direction.offset())
+            .ok_or_else(|| Error::solve(format!("The loop leads off the grid at {}", position)))?;
+        let pipe = grid[next];
+        let direction = Self::get_next_direction(pipe, direction).ok_or_else(|| {
+            Error::solve(format!(
+                "Invalid combo of moving into pipe {} from direction {:?}",
+                pipe, direction
+            ))
+        })?;
+        Ok((next, direction))
+    }
+
+    fn get_next_direction(pipe: char, direction: Direction) -> Option<Direction> {
+        match (pipe, direction) {
+            ('|', North) | ('|', South) | ('-', East) | ('-', West) | ('S', _) | ('X', _) => {
+                Some(direction)
+            }
+            ('L', West) | ('J', East) => Some(North),
+            ('7', North) | ('J', South) => Some(West),
+            ('7', East) | ('F', West) => Some(South),
+            ('L', South) | ('F', North) => Some(East),
+            _ => None,
+        }
+    }
+
+    fn valid_neighbour(pipe: char, direction: Direction) -> bool {
+        matches!(
+            (pipe, direction),
+            ('|', North)
+                | ('|', South)
+                | ('-', East)
+                | ('-', West)
+                | ('S', _)
+                | ('X', _)
+                | ('L', West)
+                | ('J', East)
+                | ('7', North)
+                | ('J', South)
+                | ('7', East)
+                | ('F', West)
+                | ('L', South)
+                | ('F', North)
+        )
+    }
+}
+
+impl Day for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::get_grid(input)
+    }
+
+    fn problem1(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let start = Self::get_start(grid)?;
+        let mut direction = Self::get_starting_direction(grid, start)?;
+
+        // Init state
+        let mut dist = 0;
+        let mut position = start;
+
+        // Iterate around the pipe
+        loop {
+            dist += 1;
+            (position, direction) = Self::step(grid, position, direction)?;
+
+            if position == start {
+                break;
+            }
+        }
+
+        // Half the distance
+        Ok((dist / 2).into())
+    }
+
+    fn problem2(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // S is replaced with its pipe, so work on a copy
+        let mut grid = grid.clone();
+        let start = Self::get_start(&grid)?;
+        let init_direction = Self::get_starting_direction(&grid, start)?;
+
+        // Init state
+        let mut direction = init_direction;
+        let mut position = start;
+
+        // Iterate around the pipe
+        let mut grid_coords: HashSet<Point<usize>> = HashSet::new();
+        loop {
+            (position, direction) = Self::step(&grid, position, direction)?;
+            grid_coords.insert(position);
+
+            if position == start {
+                break;
+            }
+        }
+
+        // Map S to the correct pipe
+        grid[start] = match (init_direction, direction) {
+            (North, North) | (South, South) => '|',
+            (East, East) | (West, West) => '-',
+            (East, North) | (South, West) => 'F',
+            (West, North) | (South, East) => '7',
+            (East, South) | (North, West) => 'L',
+            (West, South) | (North, East) => 'J',
+            _ => return Err(Error::solve("Shouldn't be able to come in from same pipe?")),
+        };
+
+        // Use Pick's theorem to find inside and outside points
+        let mut n_inside = 0;
+        for (y, row) in grid.rows().enumerate() {
+            let mut down = false;
+            let mut up = false;
+            for (x, &pipe) in row.iter().enumerate() {
+                if grid_coords.contains(&Point::new(x, y)) {
+                    match pipe {
+                        '|' => {
+                            down = !down;
+                            up = !up;
+                        }
+                        'F' | '7' => down = !down,
+                        'L' | 'J' => up = !up,
+                        _ => (),
+                    }
+                } else if up || down {
+                    n_inside += 1;
+                }
+            }
+        }
+
+        Ok(n_inside.into())
+    }
+}
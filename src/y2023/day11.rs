@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::{grid::Grid, point::Point, Answer, Day, Error, Params, ParseError};
+
+pub struct Day11 {}
+
+impl Day11 {
+    fn get_total_distance(map: &Grid<char>, dilation: usize) -> usize {
+        let empty_rows: HashSet<_> = map
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| !row.contains(&'#'))
+            .map(|(i, _)| i)
+            .collect();
+
+        let empty_cols: HashSet<_> = (0..map.width())
+            .filter(|&i| map.column(i).all(|&c| c == '.'))
+            .collect();
+
+        let mut galaxy_coords: HashSet<Point<usize>> = HashSet::new();
+        let mut y_dilation = 0;
+        for (y, row) in map.rows().enumerate() {
+            let mut x_dilation = 0;
+            for (x, &val) in row.iter().enumerate() {
+                if val == '#' {
+                    let coord = Point::new(x + x_dilation, y + y_dilation);
+                    galaxy_coords.insert(coord);
+                } else if empty_cols.contains(&x) {
+                    // subtract one to account for x increment
+                    x_dilation += dilation - 1;
+                }
+            }
+            if empty_rows.contains(&y) {
+                // subtract one to account for y increment
+                y_dilation += dilation - 1;
+            }
+        }
+
+        let mut distance = 0;
+        for (i, galaxy1) in galaxy_coords.iter().enumerate() {
+            for galaxy2 in galaxy_coords.iter().skip(i) {
+                distance += galaxy1.manhattan(*galaxy2);
+            }
+        }
+
+        distance
+    }
+}
+
+impl Day for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Grid::parse(input, |c| match c {
+            '#' | '.' => Ok(c),
+            _ => Err("Image should only contain galaxies '#' and empty space '.'".to_string()),
+        })
+    }
+
+    fn problem1(map: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::get_total_distance(map, 2).into())
+    }
+
+    fn problem2(map: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Self::problem2_with(map, &Params::default())
+    }
+
+    fn problem2_with(map: &Self::Parsed<'_>, params: &Params) -> Result<Answer, Error> {
+        let expansion = params.get_or("expansion", 1_000_000)?;
+        if expansion == 0 {
+            return Err(Error::Param {
+                key: "expansion".to_string(),
+                value: expansion.to_string(),
+                reason: "Empty space can't shrink to nothing".to_string(),
+            });
+        }
+        Ok(Self::get_total_distance(map, expansion).into())
+    }
+}
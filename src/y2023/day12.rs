@@ -1,37 +1,50 @@
-use advent_of_code::Day;
+use crate::{Answer, Day, Error, ParseError};
 
-const SRC: &str = include_str!("../../input/day12.txt");
+// The condition of each spring, and the sizes of the groups of damaged springs
+type Record<'a> = (&'a [u8], Vec<usize>);
 
 pub struct Day12 {}
 
 impl Day12 {
-    fn parse_input() -> Vec<(&'static [u8], Vec<usize>)> {
-        SRC.lines()
+    fn parse_input(input: &str) -> Result<Vec<Record<'_>>, ParseError> {
+        input
+            .lines()
             .map(|line| {
-                let (prefix, suffix) = line.split_once(' ').unwrap();
+                let (prefix, suffix) = line.split_once(' ').ok_or_else(|| {
+                    ParseError::at(input, line, "Record should be '<springs> <sizes>'")
+                })?;
+                if let Some(i) = prefix.find(|c| !"?#.".contains(c)) {
+                    return Err(ParseError::at(
+                        input,
+                        &prefix[i..],
+                        "Springs should be '?', '#' or '.'",
+                    ));
+                }
 
                 let dist = prefix.as_bytes();
                 let sizes = suffix
                     .split(',')
-                    .map(|val| val.parse().expect("Should be parsable as usize"))
-                    .collect();
+                    .map(|val| match ParseError::parse(input, val, "a group size")? {
+                        0 => Err(ParseError::at(input, val, "Group sizes should be positive")),
+                        size => Ok(size),
+                    })
+                    .collect::<Result<_, _>>()?;
 
-                (dist, sizes)
+                Ok((dist, sizes))
             })
             .collect()
     }
 
-    fn solve(repeats: usize) -> u64 {
-        let input = Self::parse_input();
+    fn solve(records: &[Record<'_>], repeats: usize) -> u64 {
         let mut output = 0;
 
-        for (base_pattern, base_springs) in input {
+        for &(base_pattern, ref base_springs) in records {
             let mut pattern = base_pattern.to_vec();
             let mut springs = base_springs.clone();
             for _ in 1..repeats {
                 pattern.push(b'?');
                 pattern.extend_from_slice(base_pattern);
-                springs.extend_from_slice(&base_springs);
+                springs.extend_from_slice(base_springs);
             }
             pattern.push(b'.');
 
@@ -43,8 +56,11 @@ impl Day12 {
                 Some(*state)
             }));
 
+            // Groups that can't fit have no arrangements
             let num_springs: usize = springs.iter().sum();
-            let wiggle = broken.len() - num_springs - springs.len();
+            let Some(wiggle) = broken.len().checked_sub(num_springs + springs.len()) else {
+                continue;
+            };
             let mut table = vec![vec![0; pattern.len()]; springs.len()];
 
             let size = springs[0];
@@ -94,11 +110,20 @@ impl Day12 {
 }
 
 impl Day for Day12 {
-    fn problem1() {
-        println!("{}", Self::solve(1));
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Parsed<'a> = Vec<Record<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse_input(input)
+    }
+
+    fn problem1(records: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::solve(records, 1).into())
     }
 
-    fn problem2() {
-        println!("{}", Self::solve(5));
+    fn problem2(records: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::solve(records, 5).into())
     }
 }
@@ -0,0 +1,88 @@
+use crate::{grid::Grid, input, Answer, Day, Error, ParseError};
+
+pub struct Day13 {}
+
+impl Day13 {
+    fn smudge_match<T: PartialEq>(a: &[T], b: &[T], smudge_available: &mut bool) -> bool {
+        if *smudge_available {
+            let diff = a.iter().zip(b.iter()).filter(|(a, b)| a != b).count();
+            if diff == 0 {
+                true
+            } else if diff == 1 {
+                *smudge_available = false;
+                true
+            } else {
+                false
+            }
+        } else {
+            a == b
+        }
+    }
+
+    fn get_n_above<T: PartialEq>(pattern: &Grid<T>, is_p2: bool) -> Option<usize> {
+        let n = pattern.height().checked_sub(1)?;
+
+        for reflection in 1..=n {
+            let mut low = reflection - 1;
+            let mut high = reflection;
+            let mut can_smudge = is_p2;
+            let mut valid =
+                Self::smudge_match(pattern.row(low), pattern.row(high), &mut can_smudge);
+            while low > 0 && high < n && valid {
+                low -= 1;
+                high += 1;
+                valid = Self::smudge_match(pattern.row(low), pattern.row(high), &mut can_smudge);
+            }
+            if valid && !can_smudge {
+                return Some(reflection);
+            }
+        }
+
+        None
+    }
+
+    fn solve(patterns: &[Grid<u8>], is_p2: bool) -> Result<usize, Error> {
+        let mut sum = 0;
+        for (i, pattern) in patterns.iter().enumerate() {
+            // Transpose columns into rows so they can reuse the row method
+            if let Some(above) = Self::get_n_above(pattern, is_p2) {
+                sum += 100 * above;
+            } else if let Some(left) = Self::get_n_above(&pattern.transposed(), is_p2) {
+                sum += left;
+            } else {
+                return Err(Error::solve(format!(
+                    "Pattern {} should have a line of reflection",
+                    i + 1
+                )));
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+impl Day for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Vec<Grid<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        input::blocks(input)
+            .map(|pattern| {
+                Grid::parse_within(input, pattern, |c| match c {
+                    '#' | '.' => Ok(c as u8),
+                    _ => Err("Expected '#' or '.'".to_string()),
+                })
+            })
+            .collect()
+    }
+
+    fn problem1(patterns: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::solve(patterns, false)?.into())
+    }
+
+    fn problem2(patterns: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::solve(patterns, true)?.into())
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::{grid::Grid, log, point::Point, Answer, Day, Error, ParseError};
+
+pub struct Day14 {}
+
+impl Day14 {
+    fn calculate_load(grid: &Grid<u8>) -> usize {
+        let rows = grid.height();
+
+        // Count number of rocks on each row
+        grid.rows()
+            .rev()
+            // Multiply load based on height of row
+            .zip(1..=rows)
+            .map(|(row, mult)| mult * row.iter().filter(|&&c| c == b'O').count())
+            .sum()
+    }
+
+    fn slide_north(grid: &mut Grid<u8>) {
+        let rows = grid.height();
+        let cols = grid.width();
+
+        for col in 0..cols {
+            // Keep track of how many rocks have been seen
+            let mut inc = 0;
+            for row in 0..rows {
+                match grid[Point::new(col, row)] {
+                    b'O' => {
+                        grid[Point::new(col, row)] = b'.';
+                        grid[Point::new(col, inc)] = b'O';
+                        inc += 1;
+                    }
+                    // Dont need to update grid value as already correct
+                    b'#' => inc = row + 1,
+                    b'.' => (),
+                    _ => unreachable!("Tiles are checked while parsing"),
+                }
+            }
+        }
+    }
+
+    fn slide_east(grid: &mut Grid<u8>) {
+        let rows = grid.height();
+        let cols = grid.width();
+
+        for row in 0..rows {
+            // Keep track of how many rocks have been seen
+            let mut inc = cols - 1;
+            for col in (0..cols).rev() {
+                match grid[Point::new(col, row)] {
+                    b'O' => {
+                        grid[Point::new(col, row)] = b'.';
+                        grid[Point::new(inc, row)] = b'O';
+                        inc = inc.saturating_sub(1);
+                    }
+                    // Dont need to update grid value as already correct
+                    b'#' if col > 0 => inc = col - 1,
+                    b'#' | b'.' => (),
+                    _ => unreachable!("Tiles are checked while parsing"),
+                }
+            }
+        }
+    }
+
+    fn slide_south(grid: &mut Grid<u8>) {
+        let rows = grid.height();
+        let cols = grid.width();
+
+        for col in 0..cols {
+            // Keep track of how many rocks have been seen
+            let mut inc = rows - 1;
+            for row in (0..rows).rev() {
+                match grid[Point::new(col, row)] {
+                    b'O' => {
+                        grid[Point::new(col, row)] = b'.';
+                        grid[Point::new(col, inc)] = b'O';
+                        inc = inc.saturating_sub(1);
+                    }
+                    // Dont need to update grid value as already correct
+                    b'#' if row > 0 => inc = row - 1,
+                    b'#' | b'.' => (),
+                    _ => unreachable!("Tiles are checked while parsing"),
+                }
+            }
+        }
+    }
+
+    fn slide_west(grid: &mut Grid<u8>) {
+        let rows = grid.height();
+        let cols = grid.width();
+
+        for row in 0..rows {
+            // Keep track of how many rocks have been seen
+            let mut inc = 0;
+            for col in 0..cols {
+                match grid[Point::new(col, row)] {
+                    b'O' => {
+                        grid[Point::new(col, row)] = b'.';
+                        grid[Point::new(inc, row)] = b'O';
+                        inc += 1;
+                    }
+                    // Dont need to update grid value as already correct
+                    b'#' => inc = col + 1,
+                    b'.' => (),
+                    _ => unreachable!("Tiles are checked while parsing"),
+                }
+            }
+        }
+    }
+}
+
+impl Day for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::parse(input, |c| match c {
+            '#' | 'O' | '.' => Ok(c as u8),
+            _ => Err("Should be either '#', 'O' or '.'".to_string()),
+        })?;
+        if grid.width() == 0 {
+            return Err(ParseError::new("Platform shouldn't be empty"));
+        }
+        Ok(grid)
+    }
+
+    fn problem1(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // Copy the grid to work in place
+        let mut grid = grid.clone();
+        Self::slide_north(&mut grid);
+        Ok(Self::calculate_load(&grid).into())
+    }
+
+    fn problem2(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut grid = grid.clone();
+        // Store previous state
+        let mut seen: HashMap<Grid<u8>, usize> = HashMap::new();
+
+        const SPINS: usize = 1000000000;
+        let mut cycles = SPINS;
+        while cycles > 0 {
+            // Check if the output is looping and then find the number of cycles after n * the loop size
+            if let Some(old) = seen.insert(grid.clone(), cycles) {
+                log::info!(
+                    "Cycle starts after {} spins with a period of {}",
+                    SPINS - old,
+                    old - cycles
+                );
+                cycles = cycles % (old - cycles);
+                seen.clear();
+            }
+            Self::slide_north(&mut grid);
+            Self::slide_west(&mut grid);
+            Self::slide_south(&mut grid);
+            Self::slide_east(&mut grid);
+            cycles -= 1;
+        }
+
+        Ok(Self::calculate_load(&grid).into())
+    }
+}
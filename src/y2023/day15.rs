@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::{Answer, Day, Error, ParseError};
+
+pub struct Day15 {}
+
+impl Day15 {
+    fn hash(s: &str) -> u8 {
+        s.bytes().fold(0u8, |acc, c| {
+            // Use overflow to prevent requiring mod
+            acc.wrapping_add(c).wrapping_mul(17)
+        })
+    }
+}
+
+impl Day for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // Allow a trailing newline, e.g. from 'echo rn=1 | advent_of_code run 15'
+        input
+            .trim_end_matches('\n')
+            .split(',')
+            .map(|step| match step.split_once(['=', '-']) {
+                Some((label, "")) => match &step[label.len()..] {
+                    "-" => Ok(step),
+                    equals => Err(ParseError::at(
+                        input,
+                        equals,
+                        "Should be a focal length after '='",
+                    )),
+                },
+                Some((_, focal)) if step.contains('=') => {
+                    match ParseError::parse::<u8>(input, focal, "a focal length")? {
+                        1..=9 => Ok(step),
+                        _ => Err(ParseError::at(
+                            input,
+                            focal,
+                            "Focal length should be between 1 and 9",
+                        )),
+                    }
+                }
+                _ => Err(ParseError::at(
+                    input,
+                    step,
+                    "Step should be '<label>=<focal length>' or '<label>-'",
+                )),
+            })
+            .collect()
+    }
+
+    fn problem1(strings: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let output: u32 = strings.iter().map(|s| Self::hash(s) as u32).sum();
+        Ok(output.into())
+    }
+
+    fn problem2(strings: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        const N_BOXES: usize = 256;
+
+        let mut boxes: Vec<Vec<&str>> = vec![Vec::new(); N_BOXES];
+        let mut box_map: HashMap<&str, &str> = HashMap::new();
+
+        for &s in strings {
+            let (k, v) = s
+                .split_once(['=', '-'])
+                .expect("Every line will contain either '-' or '='");
+            let hash = Self::hash(k) as usize;
+
+            // If it's empty we know it's '-'
+            if v.is_empty() {
+                if box_map.remove(k).is_some() {
+                    let idx = boxes[hash]
+                        .iter()
+                        .position(|&s| s == k)
+                        .expect("List will contain value when in map");
+                    boxes[hash].remove(idx);
+                }
+            } else {
+                if box_map.insert(k, v).is_none() {
+                    boxes[hash].push(k);
+                }
+            }
+        }
+
+        let mut total = 0;
+        for (box_mul, l_box) in (1..).zip(boxes) {
+            for (pos_mul, k) in (1..).zip(l_box) {
+                let focal_strength = box_map
+                    .get(k)
+                    .expect("Map keys should align with boxes array");
+                let focal_strength = focal_strength
+                    .parse::<u32>()
+                    .expect("Focal strength should be number between 1 and 9");
+                total += focal_strength * pos_mul * box_mul;
+            }
+        }
+
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::{
+    grid::Grid,
+    point::{Direction, Direction::*, Point},
+    Answer, Day, Error, ParseError,
+};
+
+pub struct Day16 {}
+
+impl Day16 {
+    fn get_next_directions(symbol: u8, direction: Direction) -> Vec<Direction> {
+        match (symbol, direction) {
+            (b'-', North) | (b'-', South) => vec![East, West],
+            (b'|', East) | (b'|', West) => vec![North, South],
+            (b'/', North) | (b'\\', South) => vec![East],
+            (b'/', East) | (b'\\', West) => vec![North],
+            (b'/', South) | (b'\\', North) => vec![West],
+            (b'/', West) | (b'\\', East) => vec![South],
+            _ => vec![direction],
+        }
+    }
+
+    fn count_energised(grid: &Grid<u8>, start: (Point<usize>, Direction)) -> usize {
+        // Create a stack of beam states (position, direction)
+        let mut seen = HashSet::new();
+        let mut beams = vec![start];
+
+        // Loop while there's still a previously unseen beam moving through the grid
+        while !beams.is_empty() {
+            let mut next_beams = Vec::new();
+            for &(position, direction) in beams.iter() {
+                // Keep track of previous values to prevent infinite loop
+                if seen.insert((position, direction)) {
+                    for new_direction in Self::get_next_directions(grid[position], direction) {
+                        // Use option to finish when beam exits grid
+                        if let Some(next) = grid.step(position, new_direction.offset()) {
+                            next_beams.push((next, new_direction));
+                        }
+                    }
+                }
+            }
+            beams = next_beams;
+        }
+
+        // Count the number of unique squares moved across
+        let seen: HashSet<Point<usize>> = seen.into_iter().map(|(position, _)| position).collect();
+        seen.len()
+    }
+}
+
+impl Day for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::parse(input, |c| match c {
+            '.' | '/' | '\\' | '|' | '-' => Ok(c as u8),
+            _ => Err("Should be one of '.', '/', '\\', '|' or '-'".to_string()),
+        })?;
+        if grid.width() == 0 {
+            return Err(ParseError::new("Contraption shouldn't be empty"));
+        }
+        Ok(grid)
+    }
+
+    fn problem1(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // Get the number of energised tiles
+        let energised = Self::count_energised(grid, (Point::new(0, 0), East));
+        Ok(energised.into())
+    }
+
+    fn problem2(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let max_x = grid.width() - 1;
+        let max_y = grid.height() - 1;
+
+        let mut max_energised = 0;
+
+        // Iterate vertical directions
+        for x in 0..=max_x {
+            // Iterate over beams coming from top of grid
+            let mut energised = Self::count_energised(grid, (Point::new(x, 0), South));
+            max_energised = max_energised.max(energised);
+
+            // Iterate over beams coming from bottom of grid
+            energised = Self::count_energised(grid, (Point::new(x, max_y), North));
+            max_energised = max_energised.max(energised);
+        }
+
+        // Iterate horizontal directions
+        for y in 0..=max_y {
+            // Iterate over beams coming from left of grid
+            let mut energised = Self::count_energised(grid, (Point::new(0, y), East));
+            max_energised = max_energised.max(energised);
+
+            // Iterate over beams coming from right of grid
+            energised = Self::count_energised(grid, (Point::new(max_x, y), West));
+            max_energised = max_energised.max(energised);
+        }
+
+        Ok(max_energised.into())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    grid::Grid,
+    point::{Direction, Direction::*, Point},
+    search, Answer, Day, Error, ParseError,
+};
+
+pub type Coord = Point<usize>;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Crucible {
+    position: Coord,
+    direction: Direction,
+    steps: u8,
+}
+
+pub struct Day17 {}
+
+impl Day17 {
+    fn moves(
+        grid: &Grid<u32>,
+        crucible: &Crucible,
+        min_step: u8,
+        max_step: u8,
+    ) -> Vec<(Crucible, usize)> {
+        let &Crucible {
+            position,
+            direction,
+            steps,
+        } = crucible;
+
+        // Crucibles can't turn around
+        [North, West, South, East]
+            .into_iter()
+            .filter(|&next| next != direction.reverse())
+            .filter(|&next| next == direction || steps >= min_step)
+            .filter_map(|next| {
+                let position = grid.step(position, next.offset())?;
+                let steps = if next == direction { steps + 1 } else { 1 };
+                let crucible = Crucible {
+                    position,
+                    direction: next,
+                    steps,
+                };
+                (steps <= max_step).then(|| (crucible, grid[position] as usize))
+            })
+            .collect()
+    }
+
+    fn least_heat_loss(grid: &Grid<u32>, min_step: u8, max_step: u8) -> Result<Answer, Error> {
+        let end = Point::new(grid.width() - 1, grid.height() - 1);
+        let starts = [East, South].map(|direction| Crucible {
+            position: Point::new(0, 0),
+            direction,
+            steps: 0,
+        });
+
+        // Heat loss is a single digit, so a bucket per total heat loss beats a heap
+        search::bucket(
+            starts,
+            |crucible| Self::moves(grid, crucible, min_step, max_step),
+            |crucible| crucible.position == end && crucible.steps >= min_step,
+        )
+        .goal_cost()
+        .map(Answer::from)
+        .ok_or_else(|| Error::solve("Should be a path to the bottom right corner"))
+    }
+}
+
+impl Day for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Parsed<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let grid = Grid::parse(input, |c| match c.to_digit(10) {
+            Some(heat_loss) => Ok(heat_loss),
+            None => Err("Heat loss should be a digit".to_string()),
+        })?;
+        if grid.width() == 0 {
+            return Err(ParseError::new("City map shouldn't be empty"));
+        }
+        Ok(grid)
+    }
+
+    fn problem1(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Self::least_heat_loss(grid, 1, 3)
+    }
+
+    fn problem2(grid: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Self::least_heat_loss(grid, 4, 10)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    point::{Direction, Direction::*, ParseDirectionError, Point},
+    Answer, Day, Error, ParseError,
+};
+
+pub struct Day18 {}
+
+impl Day18 {
+    fn calculate_area(instructions: impl Iterator<Item = (Direction, i64)>) -> i64 {
+        // Track perimeter - extra for first and last step
+        let mut perim = 2;
+        let mut total = 0;
+
+        // Track total area using shoelace formula
+        let mut position = Point::new(0, 0);
+        for (direction, steps) in instructions {
+            let next = position.step(direction, steps);
+
+            // Handle increments
+            total += position.x * next.y;
+            total -= position.y * next.x;
+            perim += steps;
+
+            // Update variables
+            position = next;
+        }
+
+        (perim + total.abs()) / 2
+    }
+}
+
+impl Day18 {
+    fn parse_instruction(input: &str, line: &str) -> Result<(Direction, i64), ParseError> {
+        let mut iter = line.split_ascii_whitespace();
+
+        // Set direction be enum
+        let direction = iter
+            .next()
+            .ok_or_else(|| ParseError::at(input, line, "Should be direction before first space"))?;
+        let direction: Direction = direction
+            .parse()
+            .map_err(|e: ParseDirectionError| ParseError::at(input, direction, e.to_string()))?;
+
+        // Cast number of steps to usize for list indexing later
+        let steps = iter
+            .next()
+            .ok_or_else(|| ParseError::at(input, line, "Should be steps after first space"))?;
+        let steps = ParseError::parse(input, steps, "a number of steps")?;
+
+        Ok((direction, steps))
+    }
+
+    fn parse_hex_instruction(input: &str, line: &str) -> Result<(Direction, i64), ParseError> {
+        let code = line.split_ascii_whitespace().nth(2).unwrap_or(line);
+
+        // Remove (# and )
+        let hex = code
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| {
+                ParseError::at(input, code, "Should end with a colour like '(#70c710)'")
+            })?;
+
+        // Parse the hex value to u32
+        let (steps, direction) = hex.split_at(5);
+        let steps = i64::from_str_radix(steps, 16).expect("Should be checked as hex already");
+
+        // Get direction as Direction Type
+        let direction = match direction {
+            "0" => East,
+            "1" => South,
+            "2" => West,
+            "3" => North,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    direction,
+                    "Last digit of the colour should be a direction from 0 to 3",
+                ))
+            }
+        };
+
+        // Return in same format as p1
+        Ok((direction, steps))
+    }
+}
+
+impl Day for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    // The instructions for each part, read from the plain and hex columns respectively
+    type Parsed<'a> = (Vec<(Direction, i64)>, Vec<(Direction, i64)>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let instructions = input
+            .lines()
+            .map(|line| Self::parse_instruction(input, line))
+            .collect::<Result<_, _>>()?;
+        let hex_instructions = input
+            .lines()
+            .map(|line| Self::parse_hex_instruction(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok((instructions, hex_instructions))
+    }
+
+    fn problem1((instructions, _): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::calculate_area(instructions.iter().copied()).into())
+    }
+
+    fn problem2((_, hex_instructions): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Ok(Self::calculate_area(hex_instructions.iter().copied()).into())
+    }
+}
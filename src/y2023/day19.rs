@@ -0,0 +1,338 @@
+use self::Operator::*;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fmt::{self, Formatter},
+};
+
+use crate::{
+    input,
+    interval::{Cuboid, Interval},
+    Answer, Day, Error, ParseError,
+};
+
+#[derive(Debug)]
+struct InvalidOperatorError;
+
+impl Display for InvalidOperatorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Operator should be '<' or '>'")
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Less,
+    Greater,
+}
+
+impl Operator {
+    fn is_valid(s: char) -> bool {
+        s == '>' || s == '<'
+    }
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = InvalidOperatorError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            ">" => Ok(Greater),
+            "<" => Ok(Less),
+            _ => Err(InvalidOperatorError),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule<'a> {
+    key: &'a str,
+    operator: Operator,
+    condition: u64,
+    target: &'a str,
+}
+
+#[derive(Debug)]
+pub struct RuleEntry<'a> {
+    rules: Vec<Rule<'a>>,
+    default: &'a str,
+}
+
+pub struct Day19 {}
+
+const RATINGS: [&str; 4] = ["x", "m", "a", "s"];
+
+impl Day19 {
+    fn deserialize_parts<'a>(
+        input: &str,
+        parts: &'a str,
+    ) -> Result<Vec<HashMap<&'a str, u64>>, ParseError> {
+        parts
+            .lines()
+            .map(|dict| {
+                // Strip '{' and '}'
+                let entries = dict
+                    .strip_prefix('{')
+                    .and_then(|dict| dict.strip_suffix('}'))
+                    .ok_or_else(|| ParseError::at(input, dict, "Part should be wrapped in '{}'"))?;
+                entries
+                    .split(',')
+                    .map(|entry| {
+                        let (k, v) = entry
+                            .split_once('=')
+                            .ok_or_else(|| ParseError::at(input, entry, "Entry should be 'k=v'"))?;
+                        let v = ParseError::parse(input, v, "a rating")?;
+                        Ok((k, v))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn deserialize_rule<'a>(input: &str, rule: &'a str) -> Result<Rule<'a>, ParseError> {
+        // Get the target and condition strings
+        let (cond, target) = rule.split_once(':').ok_or_else(|| {
+            ParseError::at(input, rule, "Rule should be a condition then target key")
+        })?;
+
+        // Split on the operator
+        let i = cond
+            .find(Operator::is_valid)
+            .ok_or_else(|| ParseError::at(input, cond, InvalidOperatorError.to_string()))?;
+        let (key, condition) = (&cond[..i], &cond[i + 1..]);
+        let operator =
+            Operator::try_from(&cond[i..i + 1]).expect("Should have found a valid operator");
+        if !RATINGS.contains(&key) {
+            return Err(ParseError::at(
+                input,
+                key,
+                "Rating should be one of 'x', 'm', 'a' or 's'",
+            ));
+        }
+
+        // convert the condition to a u64 and create the rule
+        let condition = ParseError::parse(input, condition, "a rating")?;
+        Ok(Rule {
+            key,
+            operator,
+            condition,
+            target,
+        })
+    }
+
+    fn deserialize_ruleset<'a>(
+        input: &str,
+        ruleset: &'a str,
+    ) -> Result<HashMap<&'a str, RuleEntry<'a>>, ParseError> {
+        ruleset
+            .lines()
+            .map(|line| {
+                let (key, rules) = line
+                    .strip_suffix('}')
+                    .and_then(|line| line.split_once('{'))
+                    .ok_or_else(|| {
+                        ParseError::at(input, line, "Should be a key followed by rules in '{}'")
+                    })?;
+
+                let mut rules = rules.split(',');
+                let default = rules.next_back().expect("Split should yield at least once");
+                let rules = rules
+                    .map(|rule| Self::deserialize_rule(input, rule))
+                    .collect::<Result<_, _>>()?;
+
+                Ok((key, RuleEntry { rules, default }))
+            })
+            .collect()
+    }
+
+    /// The workflows a part goes through, from 'in' until it's accepted or rejected.
+    fn route<'a>(
+        ruleset: &HashMap<&'a str, RuleEntry<'a>>,
+        part: &HashMap<&str, u64>,
+    ) -> Result<Vec<&'a str>, Error> {
+        let mut route = vec!["in"];
+        let mut rule_key = "in";
+        while rule_key != "A" && rule_key != "R" {
+            let rule_entry = Self::get_rule(ruleset, rule_key)?;
+            rule_key = rule_entry
+                .rules
+                .iter()
+                .find_map(
+                    |Rule {
+                         key,
+                         operator,
+                         condition,
+                         target,
+                     }| {
+                        part.get(key).and_then(|val| match operator {
+                            Less if val < condition => Some(*target),
+                            Greater if val > condition => Some(*target),
+                            _ => None,
+                        })
+                    },
+                )
+                .unwrap_or(rule_entry.default);
+            if route.contains(&rule_key) {
+                return Err(Error::solve(format!(
+                    "Workflow {} loops back on itself",
+                    rule_key
+                )));
+            }
+            route.push(rule_key);
+        }
+        Ok(route)
+    }
+
+    fn get_rule<'a, 'b>(
+        ruleset: &'b HashMap<&'a str, RuleEntry<'a>>,
+        rule_key: &str,
+    ) -> Result<&'b RuleEntry<'a>, Error> {
+        ruleset
+            .get(rule_key)
+            .ok_or_else(|| Error::solve(format!("Key {} should be in rule set", rule_key)))
+    }
+}
+
+impl Day for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    const QUERIES: &'static [(&'static str, &'static str)] = &[
+        ("workflow <name>", "Show a workflow's rules"),
+        (
+            "route {x=..,m=..,a=..,s=..}",
+            "Show the workflows a part goes through",
+        ),
+        ("part <n>", "Show the nth part and its route"),
+    ];
+
+    type Parsed<'a> = (HashMap<&'a str, RuleEntry<'a>>, Vec<HashMap<&'a str, u64>>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut blocks = input::blocks(input);
+        let (Some(rules), Some(parts), None) = (blocks.next(), blocks.next(), blocks.next()) else {
+            return Err(ParseError::new(
+                "File should contain list of rules, then blank line, then list of parts",
+            ));
+        };
+
+        Ok((
+            Self::deserialize_ruleset(input, rules)?,
+            Self::deserialize_parts(input, parts)?,
+        ))
+    }
+
+    fn problem1((ruleset, parts): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        let mut total = 0;
+        for part in parts {
+            if Self::route(ruleset, part)?.last() == Some(&"A") {
+                total += part.values().sum::<u64>();
+            }
+        }
+
+        Ok(total.into())
+    }
+
+    fn problem2((ruleset, _): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // Every part, with an axis per rating in the order of RATINGS
+        let mut total = 0;
+        let parts = Cuboid::new([Interval::new(1, 4001); 4]);
+
+        // Create stack of all states
+        let mut stack = Vec::new();
+        stack.push(("in", parts));
+
+        'states: while let Some((rule_key, mut parts)) = stack.pop() {
+            if rule_key == "R" {
+                continue;
+            }
+
+            if rule_key == "A" {
+                total += parts.volume();
+                continue;
+            }
+
+            let rule_entry = Self::get_rule(ruleset, rule_key)?;
+
+            for Rule {
+                key,
+                operator,
+                condition,
+                target,
+            } in rule_entry.rules.iter()
+            {
+                let axis = RATINGS
+                    .iter()
+                    .position(|rating| rating == key)
+                    .expect("Rule keys should be ratings");
+
+                // Split the parts into those taking the path and the rest
+                let (taken, rest) = match operator {
+                    Less => parts.split_at(axis, *condition),
+                    Greater => {
+                        let (rest, taken) = parts.split_at(axis, condition.saturating_add(1));
+                        (taken, rest)
+                    }
+                };
+
+                // Add parts taking path
+                if let Some(taken) = taken {
+                    stack.push((target, taken));
+                }
+
+                // Carry on with the parts not taking path, stopping if every part took it
+                match rest {
+                    Some(rest) => parts = rest,
+                    None => continue 'states,
+                }
+            }
+            stack.push((rule_entry.default, parts));
+        }
+
+        Ok(total.into())
+    }
+
+    fn explore((ruleset, parts): &mut Self::Parsed<'_>, query: &[&str]) -> Result<String, String> {
+        let show_route = |part: &HashMap<&str, u64>| {
+            let route = Self::route(ruleset, part).map_err(|e| e.to_string())?;
+            Ok(route.join(" -> "))
+        };
+
+        match query {
+            ["workflow", name] => {
+                let entry = Self::get_rule(ruleset, name).map_err(|e| e.to_string())?;
+                let rules: Vec<String> = entry
+                    .rules
+                    .iter()
+                    .map(|rule| {
+                        let operator = match rule.operator {
+                            Less => '<',
+                            Greater => '>',
+                        };
+                        format!("{}{}{}:{}", rule.key, operator, rule.condition, rule.target)
+                    })
+                    .chain([entry.default.to_string()])
+                    .collect();
+                Ok(format!("{}{{{}}}", name, rules.join(",")))
+            }
+            ["route", part] => {
+                // Parse the part as if it were the only line of an input
+                let part = Self::deserialize_parts(part, part).map_err(|e| e.to_string())?;
+                show_route(&part[0])
+            }
+            ["part", n] => {
+                let part = n
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| parts.get(n.checked_sub(1)?))
+                    .ok_or_else(|| format!("Part should be from 1 to {}", parts.len()))?;
+                let ratings: Vec<String> = RATINGS
+                    .iter()
+                    .filter_map(|&key| Some(format!("{}={}", key, part.get(key)?)))
+                    .collect();
+                Ok(format!("{{{}}}: {}", ratings.join(","), show_route(part)?))
+            }
+            _ => Err(format!("Unknown query '{}'", query.join(" "))),
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{log, math, Answer, Day, Error, Params, ParseError};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Debug,
+};
+
+#[derive(Default, Clone, Debug)]
+struct FlipFlop {
+    state: bool,
+}
+
+impl Module for FlipFlop {
+    fn tick(&mut self, signal: bool, _: String) {
+        if !signal {
+            self.state = !self.state;
+        }
+    }
+
+    fn get_state(&self) -> bool {
+        self.state
+    }
+
+    fn boxed(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+
+    fn cont(&self, signal: bool) -> bool {
+        !signal
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+struct Conjunction {
+    inputs: HashMap<String, bool>,
+    state: bool,
+}
+
+impl Module for Conjunction {
+    fn tick(&mut self, signal: bool, name: String) {
+        self.inputs.insert(name, signal);
+        self.state = !self.inputs.values().all(|&p| p);
+    }
+    fn get_state(&self) -> bool {
+        self.state
+    }
+
+    fn boxed(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+    fn account(&mut self, name: String) {
+        self.inputs.insert(name, false);
+    }
+}
+#[derive(Default, Clone, Debug)]
+struct Broadcaster {
+    state: bool,
+}
+
+impl Module for Broadcaster {
+    fn tick(&mut self, signal: bool, _: String) {
+        self.state = signal;
+    }
+    fn get_state(&self) -> bool {
+        self.state
+    }
+
+    fn boxed(&self) -> Box<dyn Module> {
+        Box::new(self.clone())
+    }
+}
+
+pub trait Module: Debug {
+    fn tick(&mut self, signal: bool, name: String);
+    fn get_state(&self) -> bool;
+    fn account(&mut self, _: String) {}
+    fn cont(&self, _: bool) -> bool {
+        true
+    }
+    fn boxed(&self) -> Box<dyn Module>;
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Self {
+        self.boxed()
+    }
+}
+
+/// A pulse sent from one module to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pulse {
+    from: String,
+    to: String,
+    high: bool,
+}
+
+type Modules = HashMap<String, Box<dyn Module>>;
+type Connections = HashMap<String, Vec<String>>;
+
+pub struct Day20 {}
+
+impl Day20 {
+    fn parse_input(input: &str) -> Result<(Modules, Connections), ParseError> {
+        let mut modules: Modules = HashMap::new();
+        let mut connections: Connections = HashMap::new();
+
+        for l in input.lines() {
+            let (src, dst) = l
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::at(input, l, "Should be '<module> -> <outputs>'"))?;
+            let (comp, name): (Box<dyn Module>, &str) = match src.trim() {
+                "broadcaster" => (Box::new(Broadcaster::default()), "broadcaster"),
+                name if name.starts_with('%') => (Box::new(FlipFlop::default()), &name[1..]),
+                name if name.starts_with('&') => (Box::new(Conjunction::default()), &name[1..]),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        src,
+                        "Module should be 'broadcaster' or start with '%' or '&'",
+                    ))
+                }
+            };
+
+            let next = dst.split(", ").map(|s| s.to_string()).collect();
+            if modules.insert(name.to_string(), comp).is_some() {
+                return Err(ParseError::at(
+                    input,
+                    src,
+                    format!("Module '{}' is defined more than once", name),
+                ));
+            }
+
+            connections.insert(name.to_string(), next);
+        }
+
+        if !modules.contains_key("broadcaster") {
+            return Err(ParseError::new("Should be a broadcaster module"));
+        }
+
+        for (key, value) in connections.iter() {
+            for module in value {
+                if let Some(n) = modules.get_mut(module) {
+                    n.account(key.clone())
+                }
+            }
+        }
+
+        Ok((modules, connections))
+    }
+
+    fn get_inputs(target: String, connections: &Connections) -> Vec<&String> {
+        connections
+            .iter()
+            .filter(|(_, module)| module.contains(&target))
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    fn get_types(keys: &[&String], modules: &Modules) -> Vec<String> {
+        keys.iter()
+            .map(|&key| modules.get(key).expect("Key should be in map"))
+            .map(|val| {
+                format!("{:?}", val)
+                    .split_once(" ")
+                    .expect("Should include ' '")
+                    .0
+                    .to_string()
+            })
+            .collect()
+    }
+
+    /// Press the button once, returning every pulse sent in the order they're sent.
+    fn press(modules: &mut Modules, connections: &Connections) -> Vec<Pulse> {
+        let mut pulses = vec![Pulse {
+            from: "button".to_string(),
+            to: "broadcaster".to_string(),
+            high: false,
+        }];
+        let mut queue = VecDeque::from([("broadcaster".to_string(), false)]);
+        while let Some((name, signal)) = queue.pop_front() {
+            for n in connections.get(&name).expect("Name is in connections") {
+                pulses.push(Pulse {
+                    from: name.clone(),
+                    to: n.clone(),
+                    high: signal,
+                });
+
+                // Untyped modules like output or rx only receive pulses
+                let Some(next) = modules.get_mut(n) else {
+                    continue;
+                };
+                if next.cont(signal) {
+                    next.tick(signal, name.clone());
+                    queue.push_back((n.to_string(), next.get_state()));
+                }
+            }
+        }
+        pulses
+    }
+}
+
+impl Day for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    const QUERIES: &'static [(&'static str, &'static str)] = &[
+        (
+            "feeds <module>",
+            "Show which modules send pulses to a module",
+        ),
+        (
+            "press [n]",
+            "Press the button n times, 1 by default, showing the pulses sent",
+        ),
+        ("state <module>", "Show a module's current state"),
+    ];
+
+    type Parsed<'a> = (Modules, Connections);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse_input(input)
+    }
+
+    fn problem1((modules, connections): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        // Pressing the button changes the state of the modules, so work on a copy
+        let mut modules = modules.clone();
+
+        let mut hc = 0;
+        let mut lc = 0;
+        for _ in 0..1000 {
+            for Pulse { high, .. } in Self::press(&mut modules, connections) {
+                match high {
+                    false => lc += 1,
+                    true => hc += 1,
+                }
+            }
+        }
+
+        Ok((hc * lc).into())
+    }
+
+    fn problem2(parsed: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Self::problem2_with(parsed, &Params::default())
+    }
+
+    fn problem2_with(
+        (modules, connections): &Self::Parsed<'_>,
+        params: &Params,
+    ) -> Result<Answer, Error> {
+        // Every cycle in a real input is a few thousand presses long
+        let limit: u64 = params.get_or("presses", 100_000)?;
+        let mut modules = modules.clone();
+
+        // Notice that rx only has one element feeding it
+        let rx_inputs = Self::get_inputs("rx".to_string(), connections);
+        let types = Self::get_types(&rx_inputs, &modules);
+        let rx_zip: Vec<(_, _)> = rx_inputs.iter().zip(types).collect::<Vec<_>>();
+        log::info!("Inputs to rx: {:?}", rx_zip);
+        if rx_inputs.len() != 1 {
+            return Err(Error::solve("Should be exactly one module feeding rx"));
+        }
+        let feeder = rx_inputs[0];
+        log::info!(
+            "rx only receives pulses from {}, so it gets a low pulse when {} sends one",
+            feeder,
+            feeder
+        );
+
+        // Notice that the feeder has a few elements feeding it
+        let feeder_inputs = Self::get_inputs(feeder.to_string(), connections);
+        let types = Self::get_types(&feeder_inputs, &modules);
+        let feeder_zip: Vec<(_, _)> = feeder_inputs.iter().zip(types).collect();
+        log::info!("Inputs to {}: {:?}", feeder, feeder_zip);
+        if feeder_inputs.is_empty() {
+            return Err(Error::solve(format!(
+                "Should be at least one module feeding {}",
+                feeder
+            )));
+        }
+        let feeder_type = modules
+            .get(feeder)
+            .map(|_| Self::get_types(&[feeder], &modules).remove(0));
+        if feeder_type.as_deref() != Some("Conjunction") {
+            return Err(Error::solve(format!(
+                "{} should be a conjunction for rx to get a low pulse",
+                feeder
+            )));
+        }
+        log::info!(
+            "{} is a conjunction, so sends a low pulse once its inputs all send high ones",
+            feeder
+        );
+        log::info!("This happens at the LCM of the cycle lengths of its inputs");
+
+        let feeder_inputs: HashSet<&String> = feeder_inputs.into_iter().collect();
+        let mut seen: HashMap<_, _> = feeder_inputs.iter().map(|&s| (s.clone(), None)).collect();
+
+        // Find the cycles
+        let mut presses: u64 = 0;
+        while seen.values().any(|val| val.is_none()) {
+            if presses == limit {
+                let mut missing: Vec<_> = seen
+                    .iter()
+                    .filter(|(_, val)| val.is_none())
+                    .map(|(name, _)| name.as_str())
+                    .collect();
+                missing.sort();
+                return Err(Error::solve(format!(
+                    "{} never sends a high pulse to {} in {} presses",
+                    missing.join(", "),
+                    feeder,
+                    limit
+                )));
+            }
+            presses += 1;
+            for Pulse { from, to, high } in Self::press(&mut modules, connections) {
+                if &to == feeder && high && seen.get(&from).is_some_and(Option::is_none) {
+                    log::info!(
+                        "{} sends a high pulse to {} after {} presses",
+                        from,
+                        feeder,
+                        presses
+                    );
+                    seen.insert(from, Some(presses));
+                }
+            }
+        }
+
+        // Calculate the lcm
+        let lcm = math::lcm_all(
+            seen.values()
+                .map(|val| val.expect("Loop should only end once every cycle is found")),
+        )
+        .ok_or_else(|| Error::solve("Presses until rx gets a low pulse should fit in a u64"))?;
+        Ok(lcm.into())
+    }
+
+    fn explore(
+        (modules, connections): &mut Self::Parsed<'_>,
+        query: &[&str],
+    ) -> Result<String, String> {
+        match query {
+            ["feeds", module] => {
+                let inputs = Self::get_inputs(module.to_string(), connections);
+                if inputs.is_empty() {
+                    return Ok(format!("Nothing sends pulses to {}", module));
+                }
+                let types = Self::get_types(&inputs, modules);
+                let inputs: Vec<String> = inputs
+                    .iter()
+                    .zip(types)
+                    .map(|(name, kind)| format!("{} ({})", name, kind))
+                    .collect();
+                Ok(inputs.join(", "))
+            }
+            ["press"] | ["press", _] => {
+                let times: usize = match query.get(1) {
+                    Some(n) => n
+                        .parse()
+                        .map_err(|_| format!("Presses should be a number, found '{}'", n))?,
+                    None => 1,
+                };
+
+                let mut lines = Vec::new();
+                let (mut low, mut high) = (0, 0);
+                for press in 1..=times {
+                    for pulse in Self::press(modules, connections) {
+                        match pulse.high {
+                            false => low += 1,
+                            true => high += 1,
+                        }
+                        if times == 1 {
+                            let signal = if pulse.high { "high" } else { "low" };
+                            lines.push(format!("{} -{}-> {}", pulse.from, signal, pulse.to));
+                        }
+                        if pulse.to == "rx" && !pulse.high {
+                            lines.push(format!("rx got a low pulse on press {}", press));
+                        }
+                    }
+                }
+                lines.push(format!("Sent {} low and {} high pulses", low, high));
+                Ok(lines.join("\n"))
+            }
+            ["state", module] => modules
+                .get(*module)
+                .map(|module| format!("{:?}", module))
+                .ok_or_else(|| format!("There's no module {}", module)),
+            _ => Err(format!("Unknown query '{}'", query.join(" "))),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{grid::Grid, point::Point, search, Answer, Day, Error, Params, ParseError};
+
+type Coord = Point<usize>;
+
+pub struct Day21 {}
+
+impl Day21 {
+    fn parse_input(input: &str) -> Result<(Grid<char>, Coord), ParseError> {
+        let mut starts = 0;
+        let mut map = Grid::parse(input, |c| match c {
+            'S' if starts > 0 => Err("Should be only one start".to_string()),
+            'S' => {
+                starts += 1;
+                Ok(c)
+            }
+            '.' | '#' => Ok(c),
+            _ => Err("Should be either '.', '#' or 'S'".to_string()),
+        })?;
+
+        let start = map
+            .find(&'S')
+            .ok_or_else(|| ParseError::new("Should be a start marked 'S'"))?;
+        map[start] = '.';
+        Ok((map, start))
+    }
+
+    /// The number of plots the elf could be on after exactly `steps` steps.
+    fn fill(map: &Grid<char>, start: Coord, steps: usize) -> usize {
+        let search = search::bfs(
+            [start],
+            |&position| map.neighbours(position).filter(|&next| map[next] == '.'),
+            |_| false,
+        );
+
+        // The elf can step back and forth to use up any even number of spare steps
+        search
+            .costs()
+            .filter(|&(_, distance)| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+}
+
+impl Day for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Parsed<'a> = (Grid<char>, Coord);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse_input(input)
+    }
+
+    fn problem1(parsed: &Self::Parsed<'_>) -> Result<Answer, Error> {
+        Self::problem1_with(parsed, &Params::default())
+    }
+
+    fn problem1_with((map, start): &Self::Parsed<'_>, params: &Params) -> Result<Answer, Error> {
+        let steps = params.get_or("steps", 64)?;
+        Ok(Self::fill(map, *start, steps).into())
+    }
+
+    fn problem2((map, start): &Self::Parsed<'_>) -> Result<Answer, Error> {
+        const STEPS: usize = 26501365;
+
+        let start = *start;
+        let len = map.height();
+
+        // The repeating pattern only works out for a square garden with the start in the middle
+        if len < 3 || map.width() != len || start != Point::new(len / 2, len / 2) {
+            return Err(Error::solve(
+                "Garden should be square with the start in the middle",
+            ));
+        }
+
+        // Get number of odd and even squares
+        let grid_width = STEPS / len - 1;
+        let mut odd_squares = (grid_width.div_ceil(2) * 2).pow(2);
+        let mut even_squares = (grid_width / 2 * 2 + 1).pow(2);
+
+        // Determine points that can be reached
+        odd_squares *= Self::fill(map, start, len * 2);
+        even_squares *= Self::fill(map, start, len * 2 + 1);
+
+        // Dertime corners - right, left, bottom, top
+        let mut corners = Self::fill(map, Point::new(len - 1, start.y), len - 1);
+        corners += Self::fill(map, Point::new(0, start.y), len - 1);
+        corners += Self::fill(map, Point::new(start.x, len - 1), len - 1);
+        corners += Self::fill(map, Point::new(start.x, 0), len - 1);
+
+        // Calculate other paritally filled segments segments
+        let mut sm_segments = Self::fill(map, Point::new(len - 1, 0), len / 2 - 1);
+        sm_segments += Self::fill(map, Point::new(len - 1, len - 1), len / 2 - 1);
+        sm_segments += Self::fill(map, Point::new(0, 0), len / 2 - 1);
+        sm_segments += Self::fill(map, Point::new(0, len - 1), len / 2 - 1);
+
+        // Compute the large segments
+        let mut lg_segments = Self::fill(map, Point::new(len - 1, 0), 3 * len / 2 - 1);
+        lg_segments += Self::fill(map, Point::new(len - 1, len - 1), 3 * len / 2 - 1);
+        lg_segments += Self::fill(map, Point::new(0, 0), 3 * len / 2 - 1);
+        lg_segments += Self::fill(map, Point::new(0, len - 1), 3 * len / 2 - 1);
+
+        // See the segments multiple times
+        sm_segments *= grid_width + 1;
+        lg_segments *= grid_width;
+        let segments = sm_segments + lg_segments;
+
+        let total = odd_squares + even_squares + corners + segments;
+        Ok(total.into())
+    }
+}
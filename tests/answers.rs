@@ -0,0 +1,73 @@
+use std::thread;
+
+use advent_of_code::{
+    answers::{Answers, Verdict},
+    input::Source,
+    registry, Params, Part,
+};
+
+#[test]
+fn registry_has_no_duplicate_or_invalid_days() {
+    assert_eq!(registry::duplicates(), vec![]);
+    assert!(registry::invalid().is_empty());
+}
+
+#[test]
+fn registered_days_match_recorded_answers() {
+    let mut failures = Vec::new();
+
+    for year in registry::years() {
+        let answers = Answers::load(year).expect("Answers should be valid");
+        // Only the parts with a recorded answer, so days still being solved are skipped
+        let entries: Vec<_> = registry::year(year)
+            .into_iter()
+            .map(|entry| {
+                let parts: Vec<Part> = Part::ALL
+                    .into_iter()
+                    .filter(|&part| answers.get(entry.day, part).is_some())
+                    .collect();
+                (entry, parts)
+            })
+            .filter(|(_, parts)| !parts.is_empty())
+            .collect();
+
+        // Some days are slow in debug builds, so solve each day on its own thread
+        let results: Vec<_> = thread::scope(|s| {
+            let handles: Vec<_> = entries
+                .iter()
+                .map(|(entry, parts)| {
+                    s.spawn(move || {
+                        let input = Source::Default.read(entry.year, entry.day)?;
+                        parts
+                            .iter()
+                            .map(|&part| entry.try_solve(part, &input, &Params::default()))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        for ((entry, parts), result) in entries.iter().zip(results) {
+            let solved = match result {
+                Ok(solved) => solved,
+                Err(e) => {
+                    failures.push(format!("{} day {}: {}", year, entry.day, e));
+                    continue;
+                }
+            };
+            for (&part, answer) in parts.iter().zip(solved) {
+                match answers.check(entry.day, part, &answer) {
+                    Verdict::Pass => (),
+                    Verdict::Fail { expected } => failures.push(format!(
+                        "{} day {} part {}: expected {}, got {}",
+                        year, entry.day, part, expected, answer
+                    )),
+                    Verdict::Unknown => unreachable!("Only parts with recorded answers are solved"),
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,156 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    client::{mock::MockServer, Client, Outcome},
+    history::{History, Verdict},
+    Answer, Part,
+};
+
+const SESSION: &str = "53616c7465645f5f";
+
+/// A fresh directory for a test's inputs, removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "advent_of_code-client-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        Self(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn client(url: &str, session: &str, dir: &TempDir) -> Client {
+    let mut client = Client::new(url, session).expect("Should accept the mock server's URL");
+    client.input_dir = dir.0.clone();
+    client.min_interval = Duration::from_millis(1);
+    client
+}
+
+fn server() -> MockServer {
+    MockServer::new(SESSION)
+        .input(2023, 1, "1abc2\npqr3stu8vwx\n")
+        .input(2023, 2, "Game 1: 3 blue\n")
+        .answer(2023, 1, Part::One, 142)
+        .answer(2023, 1, Part::Two, 281)
+}
+
+#[test]
+fn fetched_inputs_are_cached() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("cache");
+    let mut client = client(&server.url(), SESSION, &dir);
+
+    assert_eq!(client.input(2023, 1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(
+        fs::read_to_string(dir.0.join("2023").join("day01.txt")).unwrap(),
+        "1abc2\npqr3stu8vwx\n"
+    );
+    assert_eq!(client.input(2023, 1).unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(server.requests(), ["GET /2023/day/1/input"]);
+}
+
+#[test]
+fn fetching_needs_a_valid_session() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("session");
+    let mut client = client(&server.url(), "expired", &dir);
+
+    let err = client.input(2023, 1).unwrap_err();
+    assert_eq!(err.status, Some(400));
+    assert!(err.reason.contains("log in"), "{}", err);
+    assert!(!dir.0.join("2023").join("day01.txt").exists());
+}
+
+#[test]
+fn answers_are_judged_and_never_resubmitted() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("judged");
+    let mut client = client(&server.url(), SESSION, &dir);
+    let mut history = History::default();
+
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(150), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Judged(Verdict::TooHigh));
+    assert_eq!(
+        history.verdict(Part::One, &Answer::Int(150)),
+        Some(Verdict::TooHigh)
+    );
+    assert!(history.wait(std::time::SystemTime::now()).is_some());
+
+    // Known answers are rejected without asking, even while the server is waiting
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(150), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Known(Verdict::TooHigh));
+
+    // New answers have to wait for the server
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(142), &mut history);
+    assert!(matches!(outcome.unwrap(), Outcome::Waiting(_)));
+    assert_eq!(server.requests(), ["POST /2023/day/1/answer"]);
+}
+
+#[test]
+fn rate_limits_from_the_server_are_understood() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("limited");
+    let mut client = client(&server.url(), SESSION, &dir);
+
+    let mut history = History::default();
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(100), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Judged(Verdict::TooLow));
+
+    // A history that doesn't know about the wait, as if it had been lost
+    let mut history = History::default();
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(142), &mut history);
+    match outcome.unwrap() {
+        Outcome::RateLimited(Some(wait)) => assert!(wait <= Duration::from_secs(60)),
+        other => panic!("Expected to be rate limited, got {:?}", other),
+    }
+    assert_eq!(history.verdict(Part::One, &Answer::Int(142)), None);
+    assert!(history.wait(std::time::SystemTime::now()).is_some());
+}
+
+#[test]
+fn correct_answers_end_a_part() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("correct");
+    let mut client = client(&server.url(), SESSION, &dir);
+    let mut history = History::default();
+
+    let outcome = client.submit(2023, 1, Part::Two, &Answer::Int(281), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::WrongLevel);
+
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(142), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Judged(Verdict::Correct));
+    let outcome = client.submit(2023, 1, Part::One, &Answer::Int(143), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Known(Verdict::Wrong));
+
+    let outcome = client.submit(2023, 1, Part::Two, &Answer::Int(281), &mut history);
+    assert_eq!(outcome.unwrap(), Outcome::Judged(Verdict::Correct));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn requests_are_spaced_out() {
+    let server = server().start().unwrap();
+    let dir = TempDir::new("throttle");
+    let mut client = client(&server.url(), SESSION, &dir);
+    client.min_interval = Duration::from_millis(200);
+
+    let start = Instant::now();
+    client.input(2023, 1).unwrap();
+    client.input(2023, 2).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,40 @@
+use advent_of_code::{registry, Error, Params, Part};
+
+/// Inputs that no day should be able to solve, but that shouldn't make any of them panic.
+const MALFORMED: [&str; 5] = ["", "\n", "garbage", "1 2 3\n#.#\n", "a -> b\n\n{x=}\n"];
+
+#[test]
+fn malformed_inputs_are_errors_not_panics() {
+    let mut panics = Vec::new();
+
+    for entry in registry::all() {
+        for input in MALFORMED {
+            for part in Part::ALL {
+                if let Err(e @ Error::Panic { .. }) =
+                    entry.try_solve(part, input, &Params::default())
+                {
+                    panics.push(format!("{:?} part {}: {}", input, part, e));
+                }
+            }
+        }
+    }
+
+    assert!(panics.is_empty(), "\n{}", panics.join("\n"));
+}
+
+#[test]
+fn parse_errors_point_at_the_problem() {
+    let entry = registry::get(2023, 7).expect("Day 7 should be registered");
+    let input = "32T3K 765\nT55J5 68x\n";
+
+    match entry.try_solve(Part::One, input, &Params::default()) {
+        Err(Error::Parse(e)) => {
+            assert_eq!((e.day, e.line, e.column), (Some(7), 2, 7));
+            assert_eq!(
+                e.to_string(),
+                "Day 07: line 2 column 7: Expected a bet, found '68x'"
+            );
+        }
+        other => panic!("Expected a parse error, got {:?}", other),
+    }
+}
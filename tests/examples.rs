@@ -0,0 +1,43 @@
+use advent_of_code::{answers::Answers, examples, registry, Part};
+
+#[test]
+fn registered_days_solve_their_examples() {
+    let mut failures = Vec::new();
+
+    for entry in registry::all() {
+        let day_examples = examples::load(entry.year, entry.day).expect("Examples should be valid");
+        // A newly scaffolded day has no examples until its puzzle is being solved
+        let answers = Answers::load(entry.year).expect("Answers should be valid");
+        let solved = Part::ALL
+            .into_iter()
+            .any(|part| answers.get(entry.day, part).is_some());
+        if day_examples.is_empty() && solved {
+            failures.push(format!("{} day {}: no examples", entry.year, entry.day));
+        }
+
+        for example in day_examples {
+            // Every example should give the same answers whichever line endings it's saved with
+            let crlf = example.input.replace('\n', "\r\n");
+            for (endings, input) in [("LF", &example.input), ("CRLF", &crlf)] {
+                for part in Part::ALL {
+                    let Some(expected) = example.expected(part) else {
+                        continue;
+                    };
+                    match entry.try_solve(part, input, &example.params) {
+                        Ok(answer) if &answer == expected => (),
+                        Ok(answer) => failures.push(format!(
+                            "{} day {} part {} '{}' ({}): expected {}, got {}",
+                            entry.year, entry.day, part, example.name, endings, expected, answer
+                        )),
+                        Err(e) => failures.push(format!(
+                            "{} day {} part {} '{}' ({}): {}",
+                            entry.year, entry.day, part, example.name, endings, e
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}